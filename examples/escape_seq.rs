@@ -96,8 +96,10 @@ fn main() -> ! {
         riscv::interrupt::enable();
     }
 
-    let r = ansi::parse_esc_str("abcd\u{1B}[XYZ\u{1B}[");
-    println!("{:?}", r);
+    let mut parser = ansi::Parser::new();
+    println!("{:?}", parser.parse("abcd\u{1B}[XYZ\u{1B}["));
+    // The parser picks the unfinished sequence back up
+    println!("{:?}", parser.parse("2J"));
 
     // match escape.push_str("abcd\u{1B}[5") {
     //     ParseRes::InSequence(s) => {
@@ -0,0 +1,132 @@
+//! Glyph Bitmap Distribution Format, the X11 bitmap font format that most
+//! bitmap fonts (Terminus, Spleen, Tamzen, ...) are distributed in
+//!
+//! See https://adobe-type-tools.github.io/font-tech-notes/pdfs/5005.BDF_Spec.pdf
+
+use std::collections::BTreeMap;
+
+use crate::font::{Bitmap, Font};
+
+/// The font-wide bounding box, which every glyph is placed in
+struct BoundingBox {
+    width: u32,
+    height: u32,
+    x: i32,
+    y: i32,
+}
+
+pub fn parse(text: &str) -> Result<Font, String> {
+    let mut bbox = None;
+    let mut glyphs = Vec::new();
+    let mut chars = BTreeMap::new();
+
+    let mut lines = text.lines().enumerate();
+    while let Some((n, line)) = lines.next() {
+        let mut words = line.split_whitespace();
+        match words.next() {
+            Some("FONTBOUNDINGBOX") => {
+                let [width, height, x, y] = numbers(words, n)?;
+                bbox = Some(BoundingBox {
+                    width: width as u32,
+                    height: height as u32,
+                    x,
+                    y,
+                });
+            }
+            Some("STARTCHAR") => {
+                let bbox = bbox
+                    .as_ref()
+                    .ok_or(format!("line {}: STARTCHAR before FONTBOUNDINGBOX", n + 1))?;
+                let (encoding, glyph) = parse_char(&mut lines, bbox)?;
+                // Glyphs without a code point (ENCODING -1) can't be typed
+                if let Some(c) = encoding.and_then(char::from_u32) {
+                    chars.entry(c).or_insert(glyphs.len());
+                    glyphs.push(glyph);
+                }
+            }
+            _ => {}
+        }
+    }
+
+    let bbox = bbox.ok_or("no FONTBOUNDINGBOX")?;
+    Ok(Font {
+        width: bbox.width,
+        height: bbox.height,
+        // The bounding box's top is `height + y` above the baseline, and the
+        // baseline is the bottom of the row just above it
+        baseline: Some((bbox.height as i32 + bbox.y - 1).max(0) as u32),
+        glyphs,
+        chars,
+    })
+}
+
+/// Read from just after `STARTCHAR` to `ENDCHAR`, placing the glyph in the
+/// font's bounding box
+fn parse_char<'a>(
+    lines: &mut impl Iterator<Item = (usize, &'a str)>,
+    bbox: &BoundingBox,
+) -> Result<(Option<u32>, Bitmap), String> {
+    let mut encoding = None;
+    // The glyph's own bounding box, which defaults to the font's
+    let (mut width, mut height, mut x, mut y) = (bbox.width, bbox.height, bbox.x, bbox.y);
+    let mut bitmap = Bitmap::blank(bbox.width, bbox.height);
+
+    while let Some((n, line)) = lines.next() {
+        let mut words = line.split_whitespace();
+        match words.next() {
+            Some("ENCODING") => {
+                let [e] = numbers(words, n)?;
+                encoding = u32::try_from(e).ok();
+            }
+            Some("BBX") => {
+                let [w, h, bx, by] = numbers(words, n)?;
+                (width, height, x, y) = (w as u32, h as u32, bx, by);
+            }
+            Some("BITMAP") => {
+                // The glyph's top row is this far down from the font's top
+                let top = (bbox.height as i32 + bbox.y) - (height as i32 + y);
+                let left = x - bbox.x;
+                for row in 0..height as i32 {
+                    let (n, hex) = lines
+                        .next()
+                        .ok_or(format!("line {}: BITMAP cut short", n + 1))?;
+                    // Rows are padded out to whole bytes
+                    let row_bits = hex.trim().len() as u32 * 4;
+                    if row_bits > u128::BITS {
+                        return Err(format!("line {}: rows wider than 128 pixels", n + 1));
+                    }
+                    let bits = u128::from_str_radix(hex.trim(), 16)
+                        .map_err(|e| format!("line {}: {e}", n + 1))?;
+                    for col in 0..width.min(row_bits) as i32 {
+                        if bits & (1 << (row_bits as i32 - 1 - col)) == 0 {
+                            continue;
+                        }
+                        let (px, py) = (left + col, top + row);
+                        if (0..bbox.width as i32).contains(&px)
+                            && (0..bbox.height as i32).contains(&py)
+                        {
+                            bitmap.rows[py as usize][px as usize] = true;
+                        }
+                    }
+                }
+            }
+            Some("ENDCHAR") => return Ok((encoding, bitmap)),
+            _ => {}
+        }
+    }
+    Err("STARTCHAR without ENDCHAR".into())
+}
+
+/// Exactly `N` integers from the rest of line `n`
+fn numbers<'a, const N: usize>(
+    words: impl Iterator<Item = &'a str>,
+    n: usize,
+) -> Result<[i32; N], String> {
+    let values = words
+        .map(|w| w.parse::<i32>())
+        .collect::<Result<Vec<_>, _>>()
+        .map_err(|e| format!("line {}: {e}", n + 1))?;
+    values
+        .try_into()
+        .map_err(|_| format!("line {}: expected {N} numbers", n + 1))
+}
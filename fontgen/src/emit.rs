@@ -0,0 +1,138 @@
+//! Writing a font out as Rust, laid out the way `vgaterm-core/src/text.rs`
+//! has its Tamzen font
+
+use std::collections::BTreeMap;
+
+use crate::font::Font;
+
+/// Glyphs across the font image, which is as many as text.rs has
+const GLYPHS_PER_ROW: usize = 16;
+
+/// A module with the font as a `MonoFont` const called `name`, from a file
+/// called `source`
+pub fn module(font: &Font, name: &str, source: &str) -> String {
+    // Glyphs in the order of the first character that uses them, dropping
+    // the ones no character does
+    let mut order = BTreeMap::new();
+    let mut used = Vec::new();
+    for &glyph in font.chars.values() {
+        order.entry(glyph).or_insert_with(|| {
+            used.push(glyph);
+            used.len() - 1
+        });
+    }
+    let chars: Vec<(char, usize)> = font.chars.iter().map(|(&c, g)| (c, order[g])).collect();
+    let replacement = ['\u{FFFD}', '?']
+        .iter()
+        .find_map(|c| font.chars.get(c).map(|g| order[g]))
+        .unwrap_or(0);
+
+    let (width, height) = (font.width as usize, font.height as usize);
+    let image_width = GLYPHS_PER_ROW * width;
+    let row_bytes = (image_width + 7) / 8;
+    let glyph_rows = (used.len() + GLYPHS_PER_ROW - 1) / GLYPHS_PER_ROW;
+    let mut raw = vec![0u8; row_bytes * height * glyph_rows];
+    for (i, &glyph) in used.iter().enumerate() {
+        let (left, top) = ((i % GLYPHS_PER_ROW) * width, (i / GLYPHS_PER_ROW) * height);
+        for (y, row) in font.glyphs[glyph].rows.iter().enumerate() {
+            for (x, _) in row.iter().enumerate().filter(|(_, &on)| on) {
+                let x = left + x;
+                raw[(top + y) * row_bytes + x / 8] |= 0x80 >> (x % 8);
+            }
+        }
+    }
+
+    let bytes: Vec<String> = raw
+        .chunks(16)
+        .map(|line| {
+            let line: Vec<String> = line.iter().map(|b| format!("{b:#04x}")).collect();
+            format!("    {},\n", line.join(", "))
+        })
+        .collect();
+    let arms: Vec<String> = runs(&chars)
+        .into_iter()
+        .map(|(first, last, index)| {
+            let (a, b) = (literal(first), literal(last));
+            if first == last {
+                format!("            {a} => {index},\n")
+            } else if index == 0 {
+                format!("            {a}..={b} => c as usize - {a} as usize,\n")
+            } else {
+                format!("            {a}..={b} => (c as usize - {a} as usize) + {index},\n")
+            }
+        })
+        .collect();
+
+    let baseline = font.baseline();
+    // Just under the baseline, and never out of the cell
+    let underline = (baseline as usize + 1).min(height - 1);
+    let (bytes, arms, size) = (bytes.concat(), arms.concat(), raw.len());
+    format!(
+        "\
+//! {name}, generated by `cargo fontgen` from `{source}`
+//!
+//! Rather than edit this, change the font and run it again.
+
+use embedded_graphics::{{
+    image::ImageRaw,
+    mono_font::{{mapping::GlyphMapping, DecorationDimensions, MonoFont}},
+    prelude::Size,
+}};
+
+const RAW: &[u8; {size}] = &[
+{bytes}];
+
+/// The glyph for anything the font doesn't cover
+const REPLACEMENT: usize = {replacement};
+
+struct Mapping;
+
+impl GlyphMapping for Mapping {{
+    fn index(&self, c: char) -> usize {{
+        match c {{
+{arms}            _ => REPLACEMENT,
+        }}
+    }}
+}}
+
+const GLYPH_MAPPING: Mapping = Mapping;
+
+pub const {name}: MonoFont = MonoFont {{
+    image: ImageRaw::new_binary(RAW, {image_width}),
+    character_size: Size::new({width}, {height}),
+    character_spacing: 0,
+    baseline: {baseline},
+    strikethrough: DecorationDimensions::default_strikethrough({height}),
+    underline: DecorationDimensions::new({underline}, 1),
+    glyph_mapping: &GLYPH_MAPPING,
+}};
+"
+    )
+}
+
+/// Runs of consecutive characters with consecutive glyphs, as the first and
+/// last character and the first glyph
+pub fn runs(chars: &[(char, usize)]) -> Vec<(char, char, usize)> {
+    let mut runs: Vec<(char, char, usize)> = Vec::new();
+    for &(c, glyph) in chars {
+        match runs.last_mut() {
+            Some((first, last, index))
+                if *last as u32 + 1 == c as u32
+                    && *index + (c as usize - *first as usize) == glyph =>
+            {
+                *last = c
+            }
+            _ => runs.push((c, c, glyph)),
+        }
+    }
+    runs
+}
+
+/// `c` as a Rust char literal, spelled out unless it's plain ASCII
+fn literal(c: char) -> String {
+    match c {
+        '\'' | '\\' => format!("'\\{c}'"),
+        ' '..='~' => format!("'{c}'"),
+        _ => format!("'\\u{{{:04X}}}'", c as u32),
+    }
+}
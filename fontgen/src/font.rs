@@ -0,0 +1,56 @@
+//! What the font parsers read a font into, whatever format it came from
+
+use std::collections::BTreeMap;
+
+/// One glyph's pixels, a row at a time from the top
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Bitmap {
+    pub rows: Vec<Vec<bool>>,
+}
+
+impl Bitmap {
+    pub fn blank(width: u32, height: u32) -> Bitmap {
+        Bitmap {
+            rows: vec![vec![false; width as usize]; height as usize],
+        }
+    }
+
+    /// The lowest row with anything in it
+    pub fn bottom(&self) -> Option<u32> {
+        self.rows
+            .iter()
+            .rposition(|r| r.contains(&true))
+            .map(|r| r as u32)
+    }
+}
+
+/// A fixed width bitmap font
+#[derive(Debug)]
+pub struct Font {
+    pub width: u32,
+    pub height: u32,
+    /// The row of the cell the baseline is on, if the format says
+    pub baseline: Option<u32>,
+    pub glyphs: Vec<Bitmap>,
+    /// Which glyph each character is drawn with. Several characters can
+    /// share one, and glyphs nothing maps to are left out of the output.
+    pub chars: BTreeMap<char, usize>,
+}
+
+impl Font {
+    pub fn glyph(&self, c: char) -> Option<&Bitmap> {
+        self.chars.get(&c).map(|&i| &self.glyphs[i])
+    }
+
+    /// The baseline the format gave, or failing that a guess from where the
+    /// bottom of `M` or `x` is
+    pub fn baseline(&self) -> u32 {
+        self.baseline
+            .or_else(|| {
+                ['M', 'x', 'H']
+                    .iter()
+                    .find_map(|&c| self.glyph(c)?.bottom())
+            })
+            .unwrap_or(self.height - 1)
+    }
+}
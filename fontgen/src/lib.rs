@@ -0,0 +1,6 @@
+//! The font parsers and module writer behind the `fontgen` tool
+
+pub mod bdf;
+pub mod emit;
+pub mod font;
+pub mod psf;
@@ -0,0 +1,113 @@
+//! Turns a PSF2 or BDF bitmap font into a Rust module with an
+//! embedded-graphics `MonoFont` in it, for the terminal to draw text with
+//!
+//! ```text
+//! cargo fontgen ter-u16n.bdf --name TERMINUS_8X16 --output vgaterm-core/src/terminus.rs
+//! ```
+//!
+//! Only the glyphs the font has characters for make it into the module, and
+//! the `GlyphMapping` covers just those characters, drawing anything else
+//! with the font's U+FFFD (or failing that, its `?`).
+
+use std::{env, fs, path::PathBuf, process::ExitCode};
+
+use vgaterm_fontgen::{bdf, emit, psf};
+
+const USAGE: &str = "\
+usage: fontgen <FONT> --name <NAME> [--baseline <ROW>] [--output <FILE>]
+
+  FONT            a PSF2 (.psf, .psfu) or BDF (.bdf) font
+  --name NAME     what to call the MonoFont const, like TERMINUS_8X16
+  --baseline ROW  the row of the glyphs the baseline is on. BDF fonts say,
+                  and for PSF2 it's guessed from the bottom of 'M'
+  --output FILE   where to write the module, rather than to stdout";
+
+struct Args {
+    font: PathBuf,
+    name: String,
+    baseline: Option<u32>,
+    output: Option<PathBuf>,
+}
+
+fn parse_args() -> Result<Args, String> {
+    let mut font = None;
+    let mut name = None;
+    let mut baseline = None;
+    let mut output = None;
+
+    let mut args = env::args().skip(1);
+    while let Some(arg) = args.next() {
+        let mut value = || args.next().ok_or(format!("{arg} needs a value"));
+        match arg.as_str() {
+            "--name" => name = Some(value()?),
+            "--baseline" => {
+                let row = value()?;
+                baseline = Some(row.parse().map_err(|_| format!("bad baseline {row}"))?);
+            }
+            "--output" => output = Some(PathBuf::from(value()?)),
+            "-h" | "--help" => return Err(USAGE.into()),
+            _ if arg.starts_with('-') => return Err(format!("unknown option {arg}\n\n{USAGE}")),
+            _ if font.is_none() => font = Some(PathBuf::from(arg)),
+            _ => return Err(format!("more than one font\n\n{USAGE}")),
+        }
+    }
+
+    Ok(Args {
+        font: font.ok_or(USAGE)?,
+        name: name.ok_or(USAGE)?,
+        baseline,
+        output,
+    })
+}
+
+fn run() -> Result<(), String> {
+    let args = parse_args()?;
+    let data = fs::read(&args.font).map_err(|e| format!("{}: {e}", args.font.display()))?;
+
+    let mut font = if data.starts_with(&psf::MAGIC) {
+        psf::parse(&data)
+    } else if data.starts_with(b"STARTFONT") {
+        bdf::parse(&String::from_utf8_lossy(&data))
+    } else {
+        Err("not a PSF2 or BDF font".into())
+    }
+    .map_err(|e| format!("{}: {e}", args.font.display()))?;
+
+    if let Some(row) = args.baseline {
+        if row >= font.height {
+            return Err(format!("the glyphs are only {} rows tall", font.height));
+        }
+        font.baseline = Some(row);
+    }
+    if font.chars.is_empty() {
+        return Err(format!(
+            "{}: no glyphs for any characters",
+            args.font.display()
+        ));
+    }
+
+    let source = args.font.file_name().unwrap_or_default().to_string_lossy();
+    let module = emit::module(&font, &args.name, &source);
+    match args.output {
+        Some(path) => fs::write(&path, module).map_err(|e| format!("{}: {e}", path.display()))?,
+        None => print!("{module}"),
+    }
+    eprintln!(
+        "{}x{} glyphs for {} characters, baseline at row {}",
+        font.width,
+        font.height,
+        font.chars.len(),
+        font.baseline()
+    );
+    Ok(())
+}
+
+fn main() -> ExitCode {
+    match run() {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(e) => {
+            eprintln!("{e}");
+            ExitCode::FAILURE
+        }
+    }
+}
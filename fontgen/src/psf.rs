@@ -0,0 +1,101 @@
+//! PC Screen Font version 2, the format of the Linux console fonts
+//! (`/usr/share/consolefonts`, once un-gzipped)
+//!
+//! See https://www.win.tue.nl/~aeb/linux/kbd/font-formats-1.html
+
+use std::collections::BTreeMap;
+
+use crate::font::{Bitmap, Font};
+
+pub const MAGIC: [u8; 4] = [0x72, 0xB5, 0x4A, 0x86];
+
+/// The font has a table of which characters each glyph is for
+const HAS_UNICODE_TABLE: u32 = 0x01;
+/// Ends a glyph's entry in the unicode table
+const SEPARATOR: u8 = 0xFF;
+/// Starts a sequence of characters that together make up a glyph, which a
+/// character cell has no way to show
+const START_SEQUENCE: u8 = 0xFE;
+
+pub fn parse(data: &[u8]) -> Result<Font, String> {
+    let word = |i: usize| -> Result<u32, String> {
+        let bytes = data.get(i * 4..i * 4 + 4).ok_or("truncated PSF2 header")?;
+        Ok(u32::from_le_bytes(bytes.try_into().unwrap()))
+    };
+    if data.get(..4) != Some(&MAGIC[..]) {
+        return Err("not a PSF2 font".into());
+    }
+    let header_size = word(2)? as usize;
+    let flags = word(3)?;
+    let count = word(4)? as usize;
+    let glyph_size = word(5)? as usize;
+    let height = word(6)?;
+    let width = word(7)?;
+
+    if width == 0 || height == 0 {
+        return Err(format!("{width}x{height} glyphs have no pixels"));
+    }
+    let row_bytes = (width as usize + 7) / 8;
+    if glyph_size != row_bytes * height as usize {
+        return Err(format!(
+            "{glyph_size} byte glyphs can't be {width}x{height}"
+        ));
+    }
+    let bitmaps_end = count
+        .checked_mul(glyph_size)
+        .and_then(|size| size.checked_add(header_size))
+        .ok_or("truncated PSF2 glyphs")?;
+    let bitmaps = data
+        .get(header_size..bitmaps_end)
+        .ok_or("truncated PSF2 glyphs")?;
+
+    let glyphs = bitmaps
+        .chunks(glyph_size)
+        .map(|glyph| Bitmap {
+            rows: glyph
+                .chunks(row_bytes)
+                .map(|row| {
+                    (0..width as usize)
+                        .map(|x| row[x / 8] & (0x80 >> (x % 8)) != 0)
+                        .collect()
+                })
+                .collect(),
+        })
+        .collect();
+
+    let mut chars = BTreeMap::new();
+    if flags & HAS_UNICODE_TABLE != 0 {
+        let mut table = &data[bitmaps_end..];
+        for glyph in 0..count {
+            let end = table
+                .iter()
+                .position(|&b| b == SEPARATOR)
+                .ok_or("truncated PSF2 unicode table")?;
+            // Only the single characters, not the sequences after them
+            let singles = table[..end]
+                .split(|&b| b == START_SEQUENCE)
+                .next()
+                .unwrap_or_default();
+            let singles = std::str::from_utf8(singles)
+                .map_err(|e| format!("bad UTF-8 for glyph {glyph}: {e}"))?;
+            for c in singles.chars() {
+                chars.entry(c).or_insert(glyph);
+            }
+            table = &table[end + 1..];
+        }
+    } else {
+        // Without a table, glyphs are in code page order, which for the
+        // first 256 we take to be Latin-1
+        for glyph in 0..count.min(256) {
+            chars.insert(char::from(glyph as u8), glyph);
+        }
+    }
+
+    Ok(Font {
+        width,
+        height,
+        baseline: None,
+        glyphs,
+        chars,
+    })
+}
@@ -0,0 +1,187 @@
+//! The PSF2 and BDF parsers and the module writer, run on fonts small enough
+//! to write out by hand
+//!
+//! ```text
+//! cargo test-fontgen
+//! ```
+
+use vgaterm_fontgen::{bdf, emit, font::Bitmap, psf};
+
+/// A PSF2 font of 4x3 glyphs, made of `glyphs` (a byte per row) and, if
+/// given, a unicode table
+fn psf2(glyphs: &[[u8; 3]], table: Option<&[u8]>) -> Vec<u8> {
+    let header = [
+        0,                      // version
+        32,                     // header size
+        table.is_some() as u32, // flags
+        glyphs.len() as u32,    // glyph count
+        3,                      // bytes per glyph
+        3,                      // height
+        4,                      // width
+    ];
+    let mut data = psf::MAGIC.to_vec();
+    for word in header {
+        data.extend_from_slice(&word.to_le_bytes());
+    }
+    data.extend(glyphs.iter().flatten());
+    data.extend_from_slice(table.unwrap_or_default());
+    data
+}
+
+/// A bitmap from rows drawn with `#` for the pixels that are on
+fn bitmap(rows: &[&str]) -> Bitmap {
+    Bitmap {
+        rows: rows
+            .iter()
+            .map(|row| row.chars().map(|c| c == '#').collect())
+            .collect(),
+    }
+}
+
+#[test]
+fn psf2_glyphs() {
+    let glyphs = [[0x90, 0x60, 0x00], [0xF0, 0x00, 0xF0]];
+    // `x`, then `=` and `≡`, with a sequence after them that can't be shown
+    let table = "x\u{FF}=≡\u{FE}e\u{301}\u{FF}";
+    let table: Vec<u8> = table
+        .chars()
+        .flat_map(|c| match c {
+            '\u{FE}' | '\u{FF}' => vec![c as u8],
+            _ => c.to_string().into_bytes(),
+        })
+        .collect();
+    let font = psf::parse(&psf2(&glyphs, Some(&table))).unwrap();
+
+    assert_eq!((font.width, font.height), (4, 3));
+    assert_eq!(font.glyphs[0], bitmap(&["#..#", ".##.", "...."]));
+    assert_eq!(font.glyphs[1], bitmap(&["####", "....", "####"]));
+    // PSF2 doesn't say, so the baseline is the bottom of `x`
+    assert_eq!(font.baseline(), 1);
+    assert_eq!(
+        font.chars.into_iter().collect::<Vec<_>>(),
+        [('=', 1), ('x', 0), ('≡', 1)]
+    );
+}
+
+#[test]
+fn psf2_without_table() {
+    // Glyphs go to Latin-1 in order
+    let font = psf::parse(&psf2(&[[0; 3]; 3], None)).unwrap();
+    assert_eq!(
+        font.chars.into_iter().collect::<Vec<_>>(),
+        [('\0', 0), ('\u{1}', 1), ('\u{2}', 2)]
+    );
+}
+
+#[test]
+fn psf2_malformed() {
+    let font = psf2(&[[0x90, 0x60, 0x00]], None);
+    assert!(psf::parse(&font[..34]).is_err(), "truncated glyphs");
+    assert!(psf::parse(&font[..20]).is_err(), "truncated header");
+
+    // No pixels at all
+    let mut empty = font;
+    for word in [5, 6, 7] {
+        empty[word * 4..word * 4 + 4].copy_from_slice(&0u32.to_le_bytes());
+    }
+    assert!(psf::parse(&empty).is_err(), "0x0 glyphs");
+
+    // So many glyphs that their size overflows
+    let mut huge = psf2(&[], None);
+    huge[4 * 4..4 * 4 + 4].copy_from_slice(&u32::MAX.to_le_bytes());
+    huge[5 * 4..5 * 4 + 4].copy_from_slice(&u32::MAX.to_le_bytes());
+    huge[6 * 4..6 * 4 + 4].copy_from_slice(&(u32::MAX / 4).to_le_bytes());
+    assert!(psf::parse(&huge).is_err(), "too many glyphs");
+}
+
+const BDF: &str = "\
+STARTFONT 2.1
+FONT -test-tiny
+FONTBOUNDINGBOX 4 6 0 -1
+CHARS 3
+STARTCHAR A
+ENCODING 65
+BBX 3 4 1 0
+BITMAP
+40
+A0
+E0
+A0
+ENDCHAR
+STARTCHAR underscore
+ENCODING 95
+BBX 4 1 0 -1
+BITMAP
+F0
+ENDCHAR
+STARTCHAR unencoded
+ENCODING -1
+BITMAP
+F0
+F0
+F0
+F0
+F0
+F0
+ENDCHAR
+ENDFONT
+";
+
+#[test]
+fn bdf_glyphs() {
+    let font = bdf::parse(BDF).unwrap();
+
+    assert_eq!((font.width, font.height), (4, 6));
+    // The bounding box goes a row below the baseline
+    assert_eq!(font.baseline(), 4);
+    // Each glyph placed in the font's bounding box by its own
+    assert_eq!(
+        font.glyph('A'),
+        Some(&bitmap(&["....", "..#.", ".#.#", ".###", ".#.#", "...."]))
+    );
+    assert_eq!(
+        font.glyph('_'),
+        Some(&bitmap(&["....", "....", "....", "....", "....", "####"]))
+    );
+    // and the one without a code point left out
+    assert_eq!(font.chars.len(), 2);
+    assert_eq!(font.glyphs.len(), 2);
+}
+
+#[test]
+fn bdf_malformed() {
+    let cut = &BDF[..BDF.find("E0").unwrap()];
+    assert!(bdf::parse(cut).is_err(), "BITMAP cut short");
+    assert!(
+        bdf::parse(&BDF.replace("FONTBOUNDINGBOX 4 6 0 -1", "")).is_err(),
+        "no bounding box"
+    );
+    assert!(bdf::parse(&BDF.replace("40\n", "4G\n")).is_err(), "not hex");
+
+    // Zero padded past what a row can hold
+    let wide = BDF.replace("40\n", &format!("{}40\n", "0".repeat(32)));
+    assert!(bdf::parse(&wide).is_err(), "row wider than 128 pixels");
+}
+
+#[test]
+fn runs() {
+    let chars = [('a', 0), ('b', 1), ('c', 2), ('e', 3), ('x', 4), ('y', 6)];
+    assert_eq!(
+        emit::runs(&chars),
+        [('a', 'c', 0), ('e', 'e', 3), ('x', 'x', 4), ('y', 'y', 6)]
+    );
+}
+
+#[test]
+fn module() {
+    let font = bdf::parse(BDF).unwrap();
+    let module = emit::module(&font, "TINY_4X6", "tiny.bdf");
+
+    assert!(module.contains("pub const TINY_4X6: MonoFont"));
+    assert!(module.contains("'A' => 0,\n"));
+    assert!(module.contains("'_' => 1,\n"));
+    // Two glyphs of 16 across, six rows of 64 pixels
+    assert!(module.contains("const RAW: &[u8; 48]"));
+    assert!(module.contains("baseline: 4,"));
+    assert!(module.contains("underline: DecorationDimensions::new(5, 1),"));
+}
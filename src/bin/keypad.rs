@@ -15,7 +15,7 @@ use esp32c3_hal::{
 use esp32c3_hal::{Rtc, IO};
 use esp_backtrace as _;
 use esp_println::{print, println};
-use vgaterm::usb_keyboard::US_ENGLISH;
+use vgaterm::{terminal_input::Input, usb_keyboard::US_ENGLISH};
 
 core::arch::global_asm!(".global _heap_size; _heap_size = 0x8000");
 
@@ -133,7 +133,8 @@ fn main() -> ! {
             let last_char = input.key_char(&key_state);
 
             match last_char {
-                Item(ref c) => print!("{}", c),
+                Item(Input::Send(ref c)) => print!("{}", c),
+                Item(Input::Page(pages)) => println!("page {} half screens", pages),
                 WouldBlock => {
                     if kevents.is_empty() {
                         println!("\nwaiting for keyboard....");
@@ -4,7 +4,7 @@
 
 extern crate alloc;
 
-use alloc::{collections::VecDeque, string::String, vec::Vec};
+use alloc::{collections::VecDeque, vec::Vec};
 use esp32c3_hal::clock::{ClockControl, CpuClock};
 use esp32c3_hal::prelude::*;
 use esp32c3_hal::timer::TimerGroup;
@@ -16,11 +16,12 @@ use vgaterm::{
     self, perf,
     video::{self},
 };
-use vgaterm::{interrupt::Priority, usb_keyboard::US_ENGLISH, Work};
+use vgaterm::{interrupt::Priority, terminal_input::Input, usb_keyboard::US_ENGLISH, Work};
 
 use core::fmt::Write;
 
-core::arch::global_asm!(".global _heap_size; _heap_size = 0xC000");
+// The text grid lives on the heap, sized for the font in use
+core::arch::global_asm!(".global _heap_size; _heap_size = 0x11000");
 
 #[global_allocator]
 static ALLOCATOR: esp_alloc::EspHeap = esp_alloc::EspHeap::empty();
@@ -86,6 +87,7 @@ fn main() -> ! {
     );
     // vgaterm::timer::configure_timer1(peripherals.TIMG1, &clocks, &mut system.peripheral_clock_control,);
     vgaterm::timer::configure_systimer(peripherals.SYSTIMER);
+    vgaterm::configure_platform();
     // let mut host_recv = vgaterm::uart::configure0(peripherals.UART0);
     let mut serial0 =
         vgaterm::uart::make_uart0(peripherals.UART0, &mut system.peripheral_clock_control);
@@ -202,6 +204,7 @@ fn main() -> ! {
     }
 
     let mut display = vgaterm::display::Display::new();
+    display.set_tear_free(cfg!(feature = "tear_free"));
 
     // let s = PrimitiveStyleBuilder::new().stroke_color();
     // r.draw_styled(, target)
@@ -211,7 +214,9 @@ fn main() -> ! {
     vgaterm::kernel::start(io.pins.gpio3);
 
     // let mut text_display = vgaterm::display::TextDisplay::new();
-    let mut terminal = vgaterm::terminal::TextField::new();
+    // Or FONT_8X16 for 80x25, or FONT_5X8 for 128x50. The host can switch
+    // later on with `ESC [ > <n> F`.
+    let mut terminal = vgaterm::terminal::TextField::with_font(&vgaterm::text::FONT_6X12);
     // terminal.type_str("Hello World!");
     // text_display.write_text(0, vgaterm::display::COLUMNS / 2 - 4, " WELCOME!");
     // text_display.write_text(1, 0, " Welcome, Aly and Ilana, to Chez Douglass, where we will enjoy food, company, drink, and new friendships!");
@@ -242,6 +247,9 @@ fn main() -> ! {
 
     let mode = ConnectMode::ConnectHost;
 
+    // Only counts anything with the `draw_bench` feature
+    let mut bench = vgaterm::display::DrawBench::new();
+
     let mut frames = 0;
     // let mut displaying = Displaying::Waiting(video::BUFFER_SIZE);
     loop {
@@ -258,10 +266,13 @@ fn main() -> ! {
             unsafe {
                 NUM_BYTES += b.len();
             }
+            bench.received(b.len());
             b
         };
 
-        let outs = terminal.type_str(String::from_utf8_lossy(&h).as_ref());
+        // A character can straddle two reads, so leave decoding it to the
+        // terminal, which keeps the first half until the rest turns up
+        let outs = terminal.type_bytes(&h);
         if !outs.is_empty() {
             println!("op response {:?}", outs);
         }
@@ -269,7 +280,12 @@ fn main() -> ! {
 
         let last_char = input.key_char(&key_state);
         match last_char {
-            Work::Item(ref c) => {
+            Work::Item(Input::Page(pages)) => {
+                terminal.scroll_view(pages * terminal.text.rows() as isize / 2)
+            }
+            Work::Item(Input::Send(ref c)) => {
+                // Typing goes back to the live screen
+                terminal.view_live();
                 match mode {
                     ConnectMode::ConnectHost => {
                         let _ = serial0.write_str(c);
@@ -287,13 +303,17 @@ fn main() -> ! {
         // Draw the characters on the frame
         // Flush the Display to the BUFFER
         // display.flush();
-        terminal.draw_up_to(315, &mut display);
+        bench.measure(|| terminal.draw_up_to(315, &mut display));
 
         if !key_events.is_empty() {
             continue;
         }
 
-        terminal.draw(&mut display);
+        // Tear free, whatever's left waits for the screen to blank
+        if !display.tear_free() || video::take_frame_done() {
+            bench.measure(|| terminal.draw(&mut display));
+        }
+        bench.report(&mut terminal.text);
 
         unsafe {
             // this will fire no less often than once per frame
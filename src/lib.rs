@@ -10,9 +10,7 @@
 extern crate alloc;
 
 pub mod album;
-pub mod ansi;
 pub mod channel;
-pub mod color;
 pub mod display;
 pub mod gpio;
 pub mod interrupt;
@@ -21,14 +19,14 @@ pub mod keyboard;
 pub mod life;
 pub mod perf;
 pub mod spi;
-pub mod terminal;
 pub mod terminal_input;
-pub mod text;
 pub mod timer;
 pub mod uart;
 pub mod usb_keyboard;
 pub mod video;
 
+pub use vgaterm_core::{ansi, color, terminal, text};
+
 // pub use println::configure;
 pub use timer::{
     clear_timer0, configure_timer0, deadline, delay, enable_timer0_interrupt, start_timer0,
@@ -51,6 +49,14 @@ pub enum Work<T> {
     WouldBlockUntil(u64),
 }
 
+/// Point the terminal core's clock at the `SYSTIMER` and its log output at
+/// the JTAG serial console
+pub fn configure_platform() {
+    vgaterm_core::platform::configure(esp32c3_hal::systimer::SystemTimer::now, |args| {
+        esp_println::println!("{}", args)
+    });
+}
+
 pub fn hello() -> &'static str {
     "hello"
 }
@@ -313,6 +313,16 @@ enum HeldState {
     Waiting,
 }
 
+/// What a key press asks the terminal to do
+#[derive(Debug)]
+pub enum Input {
+    /// Send these to the host
+    Send(String),
+    /// Page the view back through the scrollback by this many half screens
+    /// (forward, if negative), without the host hearing about it
+    Page(isize),
+}
+
 /// Processes Keyboard input and converts the keyboard state
 /// into a character stream into a terminal
 pub struct TerminalInput {
@@ -347,12 +357,17 @@ impl TerminalInput {
         None
     }
 
-    pub fn key_char(&mut self, pressed: &PressedSet) -> Work<String> {
+    pub fn key_char(&mut self, pressed: &PressedSet) -> Work<Input> {
         if let Some(s) = self.combo(pressed) {
-            return Work::Item(s);
+            return Work::Item(Input::Send(s));
         }
         if let Some(k) = self.key(pressed) {
-            Work::Item(match k {
+            match k {
+                Key::PageUp if pressed.shift() => return Work::Item(Input::Page(1)),
+                Key::PageDown if pressed.shift() => return Work::Item(Input::Page(-1)),
+                _ => {}
+            }
+            Work::Item(Input::Send(match k {
                 Key::Lockable(low, up) => {
                     let shifted = pressed.caps_lock ^ pressed.shift();
                     if shifted {
@@ -458,7 +473,7 @@ impl TerminalInput {
                     .get(&k)
                     .unwrap_or(&String::new())
                     .to_owned(),
-            })
+            }))
         } else {
             match self.state {
                 HeldState::LongDelay(_, inst) | HeldState::ShortDelay(_, inst) => {
@@ -1,135 +0,0 @@
-use embedded_graphics::{
-    image::ImageRaw,
-    mono_font::{mapping::GlyphMapping, DecorationDimensions, MonoFont},
-    prelude::Size,
-};
-
-const RAW: &[u8; 1584] = &[
-    0x00, 0x00, 0x00, 0x10, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x85, 0x14, 0x11,
-    0x04, 0x08, 0x11, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x85, 0x14, 0x3a, 0x8a, 0x08, 0x20, 0x80,
-    0x00, 0x00, 0x00, 0x04, 0x00, 0x85, 0x3e, 0x42, 0xaa, 0x08, 0x20, 0x82, 0x08, 0x00, 0x00, 0x04,
-    0x00, 0x80, 0x14, 0x21, 0x44, 0x00, 0x40, 0x4a, 0x88, 0x00, 0x00, 0x08, 0x00, 0x80, 0x14, 0x10,
-    0x8a, 0x80, 0x40, 0x47, 0x3e, 0x03, 0xe0, 0x08, 0x00, 0x00, 0x3e, 0x09, 0x49, 0x00, 0x40, 0x4a,
-    0x88, 0x00, 0x00, 0x10, 0x00, 0x00, 0x14, 0x72, 0xa9, 0x00, 0x40, 0x42, 0x08, 0x60, 0x06, 0x10,
-    0x00, 0x80, 0x14, 0x20, 0xa6, 0x80, 0x20, 0x80, 0x00, 0x60, 0x06, 0x20, 0x00, 0x00, 0x00, 0x20,
-    0x40, 0x00, 0x20, 0x80, 0x00, 0x20, 0x00, 0x20, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x11, 0x00,
-    0x00, 0x40, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x1c, 0x70, 0x87, 0x3e, 0x13, 0xe3, 0x3e, 0x71, 0xc0,
-    0x00, 0x08, 0x08, 0x22, 0x89, 0x88, 0x82, 0x32, 0x04, 0x02, 0x8a, 0x26, 0x18, 0x10, 0x04, 0x02,
-    0x9a, 0x80, 0x84, 0x53, 0xc8, 0x04, 0x8a, 0x26, 0x18, 0x23, 0xe2, 0x04, 0xa8, 0x81, 0x0c, 0x90,
-    0x2f, 0x04, 0x71, 0xe0, 0x00, 0x40, 0x01, 0x08, 0xc8, 0x82, 0x02, 0xf8, 0x28, 0x88, 0x88, 0x20,
-    0x00, 0x23, 0xe2, 0x00, 0x88, 0x84, 0x22, 0x12, 0x28, 0x88, 0x88, 0x46, 0x18, 0x10, 0x04, 0x00,
-    0x73, 0xef, 0x9c, 0x11, 0xc7, 0x08, 0x71, 0x86, 0x18, 0x08, 0x08, 0x08, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x08, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x10, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x70, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x88, 0x8f, 0x0e, 0xf3, 0xef, 0x8e, 0x89, 0xc0,
-    0xa2, 0x82, 0x28, 0x9c, 0x89, 0x48, 0x90, 0x8a, 0x08, 0x10, 0x88, 0x80, 0xa4, 0x83, 0x6c, 0xa2,
-    0x9a, 0x28, 0xa0, 0x8a, 0x08, 0x20, 0x88, 0x80, 0xa8, 0x82, 0xaa, 0xa2, 0xaa, 0x2f, 0x20, 0x8b,
-    0xcf, 0x26, 0xf8, 0x80, 0xb0, 0x82, 0xa9, 0xa2, 0xbb, 0xe8, 0xa0, 0x8a, 0x08, 0x22, 0x88, 0x88,
-    0xa8, 0x82, 0x28, 0xa2, 0x82, 0x28, 0x90, 0x92, 0x08, 0x12, 0x88, 0x88, 0xa4, 0x82, 0x28, 0xa2,
-    0x82, 0x2f, 0x0e, 0xe3, 0xe8, 0x0e, 0x89, 0xc7, 0x22, 0xfa, 0x28, 0x9c, 0x78, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x1c, 0x01, 0xc0, 0x00, 0xf1, 0xcf, 0x1e, 0xfa, 0x28, 0xa2, 0x8a, 0x2f,
-    0x90, 0x80, 0x42, 0x00, 0x8a, 0x28, 0xa0, 0x22, 0x28, 0xa2, 0x8a, 0x21, 0x10, 0x80, 0x45, 0x00,
-    0x8a, 0x28, 0xa0, 0x22, 0x28, 0xa2, 0x51, 0x42, 0x10, 0x40, 0x48, 0x80, 0xf2, 0x2f, 0x1c, 0x22,
-    0x25, 0x2a, 0x20, 0x82, 0x10, 0x40, 0x40, 0x00, 0x82, 0x2a, 0x02, 0x22, 0x25, 0x2a, 0x50, 0x84,
-    0x10, 0x20, 0x40, 0x00, 0x82, 0x29, 0x02, 0x22, 0x22, 0x2a, 0x88, 0x84, 0x10, 0x20, 0x40, 0x00,
-    0x81, 0xc8, 0xbc, 0x21, 0xc2, 0x36, 0x88, 0x8f, 0x90, 0x10, 0x40, 0x00, 0x00, 0x40, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x10, 0x10, 0x40, 0x00, 0x00, 0x20, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x1c, 0x01, 0xc0, 0x3f, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x40, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x20, 0x08, 0x00, 0x08, 0x03, 0x80, 0x80, 0x81,
-    0x20, 0x60, 0x00, 0x00, 0x10, 0x08, 0x00, 0x08, 0x04, 0x00, 0x80, 0x00, 0x20, 0x20, 0x00, 0x00,
-    0x01, 0xcf, 0x1e, 0x79, 0xcf, 0x9e, 0xf1, 0x83, 0x24, 0x23, 0x6f, 0x1c, 0x00, 0x28, 0xa0, 0x8a,
-    0x24, 0x22, 0x88, 0x81, 0x28, 0x22, 0xa8, 0xa2, 0x01, 0xe8, 0xa0, 0x8b, 0xe4, 0x22, 0x88, 0x81,
-    0x38, 0x22, 0xa8, 0xa2, 0x02, 0x28, 0xa0, 0x8a, 0x04, 0x22, 0x88, 0x81, 0x24, 0x22, 0xa8, 0xa2,
-    0x01, 0xef, 0x1e, 0x79, 0xe4, 0x1e, 0x89, 0xc1, 0x22, 0x72, 0xa8, 0x9c, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x02, 0x00, 0x01, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x1c, 0x00, 0x06,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x06, 0x03, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x08, 0x20, 0x80, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x08, 0x20, 0x84, 0x80, 0x00, 0x00, 0x00, 0x40, 0x00, 0x00, 0x00, 0x00, 0x08, 0x20, 0x8a, 0x88,
-    0xf1, 0xeb, 0x9e, 0xfa, 0x28, 0xa2, 0x8a, 0x2f, 0x88, 0x20, 0x89, 0x00, 0x8a, 0x2c, 0x20, 0x42,
-    0x28, 0xa2, 0x52, 0x21, 0x30, 0x20, 0x60, 0x00, 0x8a, 0x28, 0x1c, 0x42, 0x28, 0xaa, 0x21, 0x42,
-    0x08, 0x20, 0x80, 0x08, 0x8a, 0x28, 0x02, 0x42, 0x25, 0x2a, 0x51, 0x44, 0x08, 0x20, 0x80, 0x08,
-    0xf1, 0xe8, 0x3c, 0x39, 0xe2, 0x36, 0x88, 0x8f, 0x88, 0x20, 0x80, 0x08, 0x80, 0x20, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x80, 0x08, 0x20, 0x80, 0x08, 0x80, 0x20, 0x00, 0x00, 0x00, 0x00, 0x03, 0x00,
-    0x06, 0x03, 0x00, 0x08, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x10, 0x10, 0x86, 0x94, 0x20, 0x00, 0x10, 0x20, 0x00, 0x00, 0x20,
-    0x00, 0x08, 0x21, 0x4b, 0x00, 0x50, 0x00, 0x08, 0x20, 0xc0, 0x22, 0x21, 0x80, 0x00, 0x00, 0x00,
-    0x08, 0x50, 0xe3, 0x80, 0x71, 0x08, 0x94, 0x22, 0x42, 0x08, 0x20, 0x82, 0x08, 0x20, 0xc4, 0x3e,
-    0x81, 0x07, 0x08, 0x22, 0x40, 0x08, 0x20, 0x82, 0x14, 0x21, 0x48, 0x20, 0x83, 0xc5, 0x3e, 0x01,
-    0x80, 0x14, 0x51, 0x45, 0x14, 0x51, 0x68, 0x3c, 0x81, 0x07, 0x08, 0x20, 0x02, 0x1c, 0x71, 0xc7,
-    0x1c, 0x71, 0xc8, 0x20, 0x71, 0x08, 0xbe, 0x20, 0x04, 0x22, 0x8a, 0x28, 0xa2, 0x8a, 0x44, 0x20,
-    0x23, 0xe0, 0x08, 0x20, 0x08, 0x22, 0x8a, 0x28, 0xa2, 0x8a, 0x63, 0xbe, 0x20, 0x00, 0x00, 0x20,
-    0x08, 0x80, 0x00, 0x00, 0x00, 0x00, 0x01, 0x00, 0x00, 0x00, 0x00, 0x00, 0x07, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x06, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x10, 0x85, 0x10, 0x10, 0x85, 0x00, 0x69, 0x01, 0x08, 0x69, 0x40, 0x00, 0x21, 0x40, 0x08, 0x21,
-    0x40, 0x00, 0xb0, 0x82, 0x14, 0xb0, 0x00, 0x02, 0x00, 0x0f, 0x80, 0x00, 0x07, 0x3c, 0x00, 0x00,
-    0x00, 0x01, 0xc0, 0x1c, 0xfb, 0xe8, 0x1c, 0x71, 0xc2, 0x12, 0x89, 0xc7, 0x1c, 0x72, 0x20, 0x26,
-    0x82, 0x08, 0x08, 0x20, 0x82, 0x12, 0xca, 0x28, 0xa2, 0x8a, 0x24, 0xa6, 0xf3, 0xcf, 0x08, 0x20,
-    0x82, 0x3a, 0xaa, 0x28, 0xa2, 0x8a, 0x23, 0x2a, 0x82, 0x08, 0x08, 0x20, 0x82, 0x12, 0x9a, 0x28,
-    0xa2, 0x8a, 0x23, 0x32, 0x82, 0x08, 0x08, 0x20, 0x82, 0x14, 0x8a, 0x28, 0xa2, 0x8a, 0x24, 0xb2,
-    0xfb, 0xef, 0x9c, 0x71, 0xc7, 0x38, 0x89, 0xc7, 0x1c, 0x71, 0xc0, 0x1c, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x20, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x40, 0x42, 0x14, 0x10, 0x00, 0x00, 0x00, 0x86, 0x80, 0x30, 0x00, 0x00, 0x20, 0x85, 0x00, 0x20,
-    0x00, 0x10, 0x11, 0x4b, 0x00, 0x48, 0x00, 0x10, 0x8a, 0x20, 0x22, 0x02, 0x07, 0x08, 0x20, 0x00,
-    0x14, 0x30, 0x00, 0x08, 0x8a, 0x28, 0xa2, 0x8b, 0xc8, 0x80, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x8a, 0x28, 0xa2, 0x8a, 0x29, 0x1c, 0x71, 0xc7, 0x1c, 0x73, 0x67, 0x1c, 0x8a, 0x28, 0xa2, 0x52,
-    0x2b, 0x02, 0x08, 0x20, 0x82, 0x08, 0xa8, 0x22, 0x8a, 0x28, 0xa2, 0x22, 0x28, 0x9e, 0x79, 0xe7,
-    0x9e, 0x79, 0xe8, 0x3e, 0x8a, 0x28, 0xa2, 0x23, 0xc8, 0xa2, 0x8a, 0x28, 0xa2, 0x8a, 0x88, 0x20,
-    0x71, 0xc7, 0x1c, 0x22, 0x0b, 0x1e, 0x79, 0xe7, 0x9e, 0x7b, 0x67, 0x1e, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x02, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x0c, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x80, 0x00, 0x00, 0x80, 0x08, 0x68, 0x00, 0x08, 0x68, 0x00, 0x00, 0x11, 0x40, 0x10, 0x11,
-    0x40, 0x0e, 0xb1, 0x01, 0x14, 0xb0, 0x00, 0x00, 0x20, 0x05, 0x08, 0x20, 0x05, 0x1c, 0x00, 0x82,
-    0x00, 0x01, 0x40, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x04, 0x00, 0x00, 0x00, 0x00, 0x02, 0x02,
-    0x71, 0xc7, 0x18, 0x61, 0x86, 0x1e, 0xf1, 0xc7, 0x1c, 0x71, 0xc0, 0x1c, 0x8a, 0x28, 0x88, 0x20,
-    0x82, 0x22, 0x8a, 0x28, 0xa2, 0x8a, 0x2f, 0xa6, 0xfb, 0xef, 0x88, 0x20, 0x82, 0x22, 0x8a, 0x28,
-    0xa2, 0x8a, 0x20, 0x2a, 0x82, 0x08, 0x08, 0x20, 0x82, 0x22, 0x8a, 0x28, 0xa2, 0x8a, 0x22, 0x32,
-    0x79, 0xe7, 0x9c, 0x71, 0xc7, 0x1c, 0x89, 0xc7, 0x1c, 0x71, 0xc0, 0x1c, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x20, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x02, 0x00, 0x00, 0x00, 0x20, 0x80, 0x10, 0x40, 0x00, 0x00, 0x00, 0x40, 0x45, 0x00, 0x10,
-    0x00, 0x30, 0x40, 0x30, 0x80, 0x00, 0x00, 0x00, 0x20, 0x80, 0x14, 0x22, 0x05, 0x38, 0x20, 0x71,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x02, 0x00, 0x3c, 0x10, 0xf2, 0x00, 0x00, 0x00, 0x00,
-    0x8a, 0x28, 0xa2, 0x8b, 0xc8, 0xbe, 0x09, 0xf4, 0x00, 0x00, 0x00, 0x00, 0x8a, 0x28, 0xa2, 0x8a,
-    0x28, 0xbf, 0x07, 0xf8, 0x00, 0x00, 0x00, 0x00, 0x8a, 0x28, 0xa2, 0x8a, 0x28, 0xbf, 0x07, 0xf8,
-    0x00, 0x00, 0x00, 0x00, 0x8a, 0x28, 0xa2, 0x8a, 0x28, 0xbe, 0x09, 0xf4, 0x00, 0x00, 0x00, 0x00,
-    0x79, 0xe7, 0x9e, 0x7b, 0xc7, 0xbc, 0x10, 0xf2, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x0a,
-    0x00, 0xb8, 0x20, 0x71, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x72, 0x07, 0x30, 0x40, 0x30,
-    0x80, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x20, 0x80, 0x10, 0x40, 0x00, 0x00, 0x00,
-];
-
-struct Mapping;
-
-impl GlyphMapping for Mapping {
-    fn index(&self, c: char) -> usize {
-        match c {
-            ' '..='~' => c as usize - ' ' as usize,
-            '¡'..='¦' => (c as usize - '¡' as usize) + 95,
-            '°'..='°' => (c as usize - '°' as usize) + 101,
-            '¿'..='ÿ' => (c as usize - '¿' as usize) + 102,
-            ''..='' => (c as usize - '' as usize) + 167,
-            _ => '' as usize - ' ' as usize,
-        }
-    }
-}
-
-const GLYPH_MAPPING: Mapping = Mapping;
-
-#[allow(non_upper_case_globals)]
-pub const TAMZEN_FONT_6x12: MonoFont = MonoFont {
-    image: ImageRaw::new_binary(RAW, 96),
-    character_size: Size::new(6, 12),
-    character_spacing: 0,
-    baseline: 11,
-    strikethrough: DecorationDimensions::default_strikethrough(12),
-    underline: DecorationDimensions::default_underline(12),
-    glyph_mapping: &GLYPH_MAPPING,
-};
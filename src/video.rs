@@ -1,8 +1,11 @@
-use esp32c3_hal::prelude::*;
+use core::ops::Range;
+
+use esp32c3_hal::{prelude::*, systimer::SystemTimer};
 use esp_println::println;
 
 use crate::{
     color::{byte_to_rgb3, color3_to_byte, rgb_from_byte, Rgb3},
+    kernel,
     spi::{
         self,
         Instance::{ReadyToSend, TxInProgress},
@@ -10,11 +13,43 @@ use crate::{
     timer,
 };
 
-pub const WIDTH: usize = 640;
-pub const HEIGHT: usize = 400;
-pub const BUFFER_SIZE: usize = WIDTH * HEIGHT;
+pub use vgaterm_core::framebuffer::{BUFFER_SIZE, HEIGHT, WIDTH};
 pub static mut BUFFER: [u8; BUFFER_SIZE] = [0; BUFFER_SIZE];
 
+/// The row of `BUFFER` sent first, at the top of the screen. The rows after it
+/// follow, wrapping around from the last to the first, so moving it scrolls
+/// the whole screen without touching a pixel.
+pub static mut TOP: usize = 0;
+
+/// `TOP` as it was when the frame being sent started, so a scroll part way
+/// through doesn't show until the next
+static mut FRAME_TOP: usize = 0;
+
+/// Scroll the screen up by `rows` pixel rows (down, if negative). The rows that
+/// go off one edge come back on at the other, for the caller to draw over.
+pub fn scroll(rows: isize) {
+    riscv::interrupt::free(|| unsafe {
+        TOP = (TOP as isize + rows).rem_euclid(HEIGHT as isize) as usize;
+    });
+}
+
+/// The part of `BUFFER` sent `offset` bytes into a frame starting at row
+/// `top`: the rest of the `CHUNK_SIZE` chunk `offset` is in, or only as far as
+/// the end of `BUFFER` when the chunk runs past it. The rest of that chunk is
+/// the next part, from the start of `BUFFER`, so each part is sent in one go.
+fn part(top: usize, offset: usize) -> Range<usize> {
+    let start = (top * WIDTH + offset) % BUFFER_SIZE;
+    let chunk_end = (offset / CHUNK_SIZE + 1) * CHUNK_SIZE;
+    start..start + (chunk_end - offset).min(BUFFER_SIZE - start)
+}
+
+/// `part(top, offset)` of `BUFFER`
+///
+/// SAFETY: the slice aliases `BUFFER`, like everything else sent from it
+unsafe fn chunk(top: usize, offset: usize) -> &'static mut [u8] {
+    &mut BUFFER[part(top, offset)]
+}
+
 ///
 /// Transmit the contents of the buffer via SPI to the monitor
 /// control hardware.
@@ -36,15 +71,12 @@ pub fn transmit_frame() {
         // crate::perf::Measure::start([first_block, full_frame]);
         // crate::perf::pause_event_counter();
 
-        spi::transmit(&mut BUFFER[0..32000]);
-        // crate::perf::Measure::stop([first_block]);
-        spi::transmit(&mut BUFFER[32000..64000]);
-        spi::transmit(&mut BUFFER[64000..96000]);
-        spi::transmit(&mut BUFFER[96000..128000]);
-        spi::transmit(&mut BUFFER[128000..160000]);
-        spi::transmit(&mut BUFFER[160000..192000]);
-        spi::transmit(&mut BUFFER[192000..224000]);
-        spi::transmit(&mut BUFFER[224000..256000]);
+        let mut offset = 0;
+        while offset < BUFFER_SIZE {
+            let data = chunk(TOP, offset);
+            offset += data.len();
+            spi::transmit(data);
+        }
         // crate::perf::Measure::stop([full_frame]);
 
         // crate::perf::Measure::flush([first_block, full_frame]);
@@ -52,9 +84,45 @@ pub fn transmit_frame() {
     });
 }
 
+/// How far into the frame the part going out starts
 pub static mut OFFSET: usize = 0;
 const CHUNK_SIZE: usize = 32000;
-const LAST_CHUNK: usize = 224000;
+/// How long a whole `CHUNK_SIZE` chunk is given to go out, in microseconds.
+/// A part of one gets its share of that, so a frame takes as long whether or
+/// not it's split at the end of `BUFFER`.
+const CHUNK_TIME: u64 = 1200;
+
+/// Whether a frame is going out, from its first chunk until its last is done
+static mut SENDING: bool = false;
+/// Set when the last chunk of a frame is done, until [`take_frame_done`]
+static mut FRAME_DONE: bool = false;
+/// When the screen is next due to start a frame, as a `SystemTimer` count
+static mut BLANKING_ENDS: u64 = 0;
+
+/// Whether a frame has finished going out since the last time this was asked,
+/// which leaves the screen blanked for `kernel::BLANKING_WAIT_TIME`: the time
+/// to draw anything that should show all at once.
+pub fn take_frame_done() -> bool {
+    riscv::interrupt::free(|| unsafe { core::mem::take(&mut FRAME_DONE) })
+}
+
+/// The rows of the picture `BUFFER` can be drawn on without tearing: the
+/// ones after the part going out and the one after it, which might start
+/// before the drawing's done. All of them while the screen is blanked.
+pub fn drawable_rows() -> Range<usize> {
+    riscv::interrupt::free(|| unsafe {
+        let offset = if SENDING {
+            OFFSET
+        } else if SystemTimer::now() < BLANKING_ENDS {
+            return 0..HEIGHT;
+        } else {
+            // Late enough that the next frame could start any moment
+            0
+        };
+        let start = (offset + 2 * CHUNK_SIZE + WIDTH - 1) / WIDTH;
+        start.min(HEIGHT)..HEIGHT
+    })
+}
 
 #[link_section = ".rwtext"]
 pub fn transmit_chunk() {
@@ -78,12 +146,19 @@ pub fn transmit_chunk() {
         i => i,
     });
 
-    let data = unsafe { &mut BUFFER[OFFSET..OFFSET + CHUNK_SIZE] };
+    let data = unsafe {
+        if OFFSET == 0 {
+            FRAME_TOP = TOP;
+            SENDING = true;
+        }
+        chunk(FRAME_TOP, OFFSET)
+    };
+    let time = CHUNK_TIME * data.len() as u64 / CHUNK_SIZE as u64;
     // crate::perf::Measure::start([start_xmit]);
     spi::start_transmit(data);
     // crate::perf::Measure::stop([start_xmit]);
 
-    timer::start_timer0_callback(1200, timer_callback);
+    timer::start_timer0_callback(time, timer_callback);
     // crate::perf::Measure::stop([xmit_chunk]);
     // crate::perf::Measure::flush([start_xmit, tx_wait, xmit_chunk]);
 }
@@ -91,9 +166,15 @@ pub fn transmit_chunk() {
 #[link_section = ".rwtext"]
 fn timer_callback() {
     let offset = unsafe { &mut OFFSET };
-    if *offset < LAST_CHUNK {
-        *offset += CHUNK_SIZE;
+    *offset += part(unsafe { FRAME_TOP }, *offset).len();
+    if *offset < BUFFER_SIZE {
         transmit_chunk();
+    } else {
+        unsafe {
+            SENDING = false;
+            FRAME_DONE = true;
+            BLANKING_ENDS = timer::deadline(kernel::BLANKING_WAIT_TIME);
+        }
     }
 }
 
@@ -0,0 +1,867 @@
+//! Escapes:
+//! in the grammar, <x> will denote a variable.
+//! ==============================
+//! ESC [ H             => Cursor to (0, 0)
+//! ESC [ <r> ; <c> H   => Cursor to line r, column c
+//! ESC [ <r> ; <c> f   => Cursor to line r, column c
+//! ESC [ <n> A         => Cursor up n lines
+//! ESC [ <n> B         => Cursor down n lines
+//! ESC [ <n> C         => Cursor right n columns
+//! ESC [ <n> D         => Cursor left n columns
+//! ESC [ <n> E         => Cursor to beginning of next line, n lines down
+//! ESC [ <n> F         => Cursor to beginning of prev line, n lines up
+//! ESC [ <n> G         => Cursor to column n
+//! ESC [ <n> S         => Scroll up n lines
+//! ESC [ <n> T         => SCroll down n lines
+//! ESC [ <t> ; <b> r   => Scroll only lines t through b (the whole screen if omitted)
+//! ESC D               => Cursor down a line, scrolling up at the bottom of the scroll region
+//! ESC M               => Cursor up a line, scrolling down at the top of the scroll region
+//! HT (0x09)           => Cursor on to the next tab stop, or the end of the line if there are no more
+//! ESC H               => Set a tab stop at the cursor's column (stops start out every 8 columns)
+//! ESC [ g             => Clear the tab stop at the cursor's column
+//! ESC [ 3 g           => Clear all tab stops
+//! ESC [ <n> I         => Cursor forward n tab stops
+//! ESC [ <n> Z         => Cursor back n tab stops, or to the start of the line
+//! ESC [ 6 n           => Request cursor postion, as `ESC [ <r> ; <c> R` at row r and column c
+//! ESC ( 0             => Designate DEC Special Graphics (line drawing) as G0
+//! ESC ( B             => Designate US ASCII as G0
+//! ESC ) 0             => Designate DEC Special Graphics as G1 (also `ESC ) B` for ASCII)
+//! SO (0x0E)           => Print from G1
+//! SI (0x0F)           => Print from G0
+//! ESC 7               => Save cursor position
+//! ESC 8               => Restore cursor position
+//! ESC [ s             => Save cursor position
+//! ESC [ u             => Restore cursor position
+//! ESC [ 3 > ~         => Delete
+//! ESC [ J             => Erase from cursor until end of screen
+//! ESC [ 0 J           => Erase from cursor until end of screen
+//! ESC [ 1 J           => Erase from cursor to beginning of screen
+//! ESC [ 2 J           => Erase entire screen
+//! ESC [ K             => Erase from cursor until end of line
+//! ESC [ 0 K           => Erase start of line to cursor
+//! ESC [ 1 K           => Erase start of line to the cursor
+//! ESC [ 2 K           => Erase entire line
+//! ESC [ <n> L         => Insert n blank lines at the cursor
+//! ESC [ <n> M         => Delete n lines at the cursor
+//! ESC [ <n> @         => Insert n blank characters at the cursor
+//! ESC [ <n> P         => Delete n characters at the cursor
+//! ESC [ <n> X         => Erase n characters from the cursor
+//! ESC [ <n> SP q      => Cursor shape: 0 or 1 blinking block, 2 steady block, 3 blinking underline,
+//!                        4 steady underline, 5 blinking bar, 6 steady bar
+//! ESC [ ? 25 l        => Hide Cursor
+//! ESC [ ? 25 H        => Show Cursor
+//! ESC [ ? 7 h         => Wrap onto the next line after printing in the last column (the default)
+//! ESC [ ? 7 l         => Stay in the last column, writing each character over the last
+//! ESC [ ? 1049 h      => Save the cursor and switch to a blank alternate screen
+//! ESC [ ? 1049 l      => Switch back to the primary screen and restore the cursor
+//! ESC [ ? 47 h        => Switch to a blank alternate screen (also `? 1047`)
+//! ESC [ ? 47 l        => Switch back to the primary screen (also `? 1047`)
+//! ESC [ > V           => (vgaterm) Redraw the whole screen
+//! ESC [ V x D         => (vgaterm) Redraw the whole screen, as it was first spelled
+//! ESC [ > 1 V         => (vgaterm) Debug dump
+//! ESC [ > <n> F       => (vgaterm) Switch to font n: 0 for 6x12 (105x33), 1 for 8x13 glyphs in 8x16 cells (80x25),
+//!                        2 for 5x8 (128x50). Only font 0 has box drawing and the DEC special graphics
+//!
+//! Graphics/Colors
+//! ===============
+//! ESC [ <fg>;<bg>; m  => Set fg color between 30-37; 90-97. bg color between 40-47, 100-107
+//! ESC [ <fg>;<bg>; m => Set fg/bg colors to "bold" or "bright"
+//! ESC [ 38; 5; <c> m  => Set fg color to c where c is a color index of 256 colors
+//! ESC [ 48; 5; <c> m  => Set bg color to c where c is a color index of 256 colors
+//! ESC [ 38; 2; <r>; <g>; <b> m => Set fg color to the 24 bit color r, g, b
+//! ESC [ 48; 2; <r>; <g>; <b> m => Set bg color to the 24 bit color r, g, b
+//! ESC [ 38: 5: <c> m  => As above, but with colons (also `38:2::<r>:<g>:<b>`)
+//! ESC [ m             => Reset all colors to "default"
+//! ESC [ 0 m           => Reset all colors to "default"
+//! ESC [ 1 m           => Set "bold" mode (perhaps use the "bright" set of colors)
+//! ESC [ 2 m           => Set "dim" mode
+//! ESC [ 22 m          => Reset "dim" or "bold" mode
+//! ESC [ 3 m           => set italic mode
+//! ESC [ 23 m          => Unset italic mode
+//! ESC [ 4 m           => set underline mode
+//! ESC [ 24 m          => unset underline mode
+//! ESC [ 5 m           => set blinking mode
+//! ESC [ 25 m          => unset blinking mode
+//! ESC [ 7 m           => set inverse mode
+//! ESC [ 27 m          => unset inverse mode
+//! ESC [ 9 m           => set strikethrough
+//! ESC [ 29 m          => unset strikethrough
+//!
+//! Parsing
+//! =======
+//! Input goes through a [`Parser`], the state machine from Paul Williams' DEC
+//! compatible parser (https://vt100.net/emu/dec_ansi_parser). It keeps its state
+//! between calls, so a sequence split across two reads from the host picks up
+//! where it left off.
+//!
+//! Unlike Williams' parser, which runs on bytes, this one is fed a `char` at a
+//! time. UTF-8 is decoded in front of it, by [`crate::utf8::Utf8Decoder`], so
+//! the C1 controls reach it as the chars U+0080 to U+009F rather than as bytes.
+//! [`Parser::advance`] never allocates: every op it finds, down to each
+//! parameter of an SGR, goes straight to `emit`.
+//!
+//! Every well-formed sequence is consumed, including the ones we don't act on
+//! (and OSC/DCS strings, which we skip entirely). Sequences that mean something
+//! to the terminal come out of the parser as an [`Op`].
+//!
+
+use alloc::vec::Vec;
+
+use crate::display::CursorShape;
+
+const ESC: char = '\u{1B}';
+
+/// Like DEC's terminals, parameters past the 16th are dropped
+const MAX_PARAMS: usize = 16;
+const MAX_INTERMEDIATES: usize = 2;
+/// Parameter values saturate rather than overflow, the same cap xterm uses
+const MAX_PARAM_VALUE: u16 = 9999;
+
+#[derive(Debug)]
+pub enum Op {
+    MoveCursorDelta { dx: isize, dy: isize },
+    MoveCursorAbs { x: usize, y: usize },
+    MoveCursorAbsCol { x: usize },
+    MoveCursorBeginningAndLine { dy: isize },
+    Scroll { delta: isize },
+    SetScrollRegion { top: Option<usize>, bottom: Option<usize> },
+    Index,
+    ReverseIndex,
+    SetTabStop,
+    ClearTabStop,
+    ClearTabStops,
+    TabForward { n: usize },
+    TabBackward { n: usize },
+    /// Set which character set G0 (`g` = 0) or G1 (`g` = 1) holds
+    DesignateCharset { g: usize, charset: Charset },
+    RequstCursorPos,
+    SetCursorStyle { shape: CursorShape, blinking: bool },
+    SaveCursorPos,
+    RestoreCursorPos,
+    EraseScreen(EraseMode),
+    EraseLine(EraseMode),
+    InsertLines { n: usize },
+    DeleteLines { n: usize },
+    InsertChars { n: usize },
+    DeleteChars { n: usize },
+    EraseChars { n: usize },
+    TextOp(TextOp),
+    InPlaceDelete,
+    DecPrivateSet(u16),
+    DecPrivateReset(u16),
+    Vgaterm(Vgaterm),
+}
+
+#[derive(Debug)]
+pub enum TextOp {
+    SetBGBasic { bg: u8 },
+    SetFGBasic { fg: u8 },
+    SetFGColor256 { fg: u8 },
+    SetBGColor256 { bg: u8 },
+    SetFGColorRgb { r: u8, g: u8, b: u8 },
+    SetBGColorRgb { r: u8, g: u8, b: u8 },
+    ResetColors,
+    SetTextMode(SetUnset, Style),
+}
+
+#[derive(Debug)]
+pub enum Style {
+    Bold,
+    Dim,
+    Italic,
+    Strike,
+    Underline,
+    Blinking,
+    Inverse,
+}
+
+#[derive(Debug)]
+pub enum SetUnset {
+    Set,
+    Unset,
+}
+
+#[derive(Debug)]
+pub enum EraseMode {
+    FromCursor,
+    ToCursor,
+    All,
+}
+
+/// The character sets that can be designated into G0 and G1
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum Charset {
+    #[default]
+    Ascii,
+    /// The VT100's line drawing set, which swaps `_` through `~` for box
+    /// pieces and symbols
+    DecSpecialGraphics,
+}
+
+impl Charset {
+    /// The character `c` stands for in this set
+    pub fn map(self, c: char) -> char {
+        match self {
+            Charset::Ascii => c,
+            Charset::DecSpecialGraphics => match c {
+                '_' => ' ',
+                '`' => '◆',
+                'a' => '▒',
+                'b' => '␉',
+                'c' => '␌',
+                'd' => '␍',
+                'e' => '␊',
+                'f' => '°',
+                'g' => '±',
+                'h' => '␤',
+                'i' => '␋',
+                'j' => '┘',
+                'k' => '┐',
+                'l' => '┌',
+                'm' => '└',
+                'n' => '┼',
+                'o' => '⎺',
+                'p' => '⎻',
+                'q' => '─',
+                'r' => '⎼',
+                's' => '⎽',
+                't' => '├',
+                'u' => '┤',
+                'v' => '┴',
+                'w' => '┬',
+                'x' => '│',
+                'y' => '≤',
+                'z' => '≥',
+                '{' => 'π',
+                '|' => '≠',
+                '}' => '£',
+                '~' => '·',
+                _ => c,
+            },
+        }
+    }
+}
+
+#[derive(Debug)]
+pub enum Vgaterm {
+    Redraw,
+    /// Switch to the font numbered `n` in `text::FONTS`
+    SelectFont(usize),
+    DebugDump
+}
+
+/// What comes out of the [`Parser`]: either a character to print (or a C0/C1
+/// control to execute), or a recognized escape sequence
+#[derive(Debug)]
+pub enum OpChar {
+    Char(char),
+    Op(Op),
+}
+
+impl From<char> for OpChar {
+    fn from(value: char) -> Self {
+        OpChar::Char(value)
+    }
+}
+
+/// The numeric parameters of a control sequence. Parameters are separated by
+/// `;`, and each may carry sub-parameters separated by `:`, as in `38:5:196`.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Params {
+    values: [u16; MAX_PARAMS],
+    /// Bit i is set when `values[i]` was introduced by a `:`, i.e. it's a
+    /// sub-parameter of the one before it
+    sub: u16,
+    len: usize,
+    /// Set once a 17th parameter shows up, after which everything is dropped
+    full: bool,
+}
+
+impl Params {
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// The i-th value, counting sub-parameters, or 0 when it wasn't given
+    pub fn get(&self, i: usize) -> u16 {
+        if i < self.len {
+            self.values[i]
+        } else {
+            0
+        }
+    }
+
+    /// The i-th value, where a missing or 0 value means `default`. This is how
+    /// nearly every count or position parameter is interpreted.
+    pub fn get_or(&self, i: usize, default: u16) -> u16 {
+        match self.get(i) {
+            0 => default,
+            n => n,
+        }
+    }
+
+    /// Each parameter together with its sub-parameters: `1;38:5:196` yields
+    /// `[1]` and then `[38, 5, 196]`
+    pub fn groups(&self) -> Groups<'_> {
+        Groups { params: self, i: 0 }
+    }
+
+    fn clear(&mut self) {
+        *self = Params::default();
+    }
+
+    fn is_sub(&self, i: usize) -> bool {
+        self.sub & (1 << i) != 0
+    }
+
+    /// Feed one of `0-9`, `:` or `;`
+    fn push(&mut self, c: char) {
+        if self.full {
+            return;
+        }
+        if self.len == 0 {
+            // The first digit or separator starts the first parameter
+            self.len = 1;
+        }
+        match c {
+            '0'..='9' => {
+                let v = &mut self.values[self.len - 1];
+                *v = v
+                    .saturating_mul(10)
+                    .saturating_add(c as u16 - '0' as u16)
+                    .min(MAX_PARAM_VALUE);
+            }
+            _ if self.len == MAX_PARAMS => self.full = true,
+            _ => {
+                if c == ':' {
+                    self.sub |= 1 << self.len;
+                }
+                self.len += 1;
+            }
+        }
+    }
+}
+
+pub struct Groups<'a> {
+    params: &'a Params,
+    i: usize,
+}
+
+impl<'a> Iterator for Groups<'a> {
+    type Item = &'a [u16];
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.i >= self.params.len {
+            return None;
+        }
+        let start = self.i;
+        self.i += 1;
+        while self.i < self.params.len && self.params.is_sub(self.i) {
+            self.i += 1;
+        }
+        Some(&self.params.values[start..self.i])
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+enum State {
+    #[default]
+    Ground,
+    Escape,
+    EscapeIntermediate,
+    CsiEntry,
+    CsiParam,
+    CsiIntermediate,
+    CsiIgnore,
+    DcsEntry,
+    DcsParam,
+    DcsIntermediate,
+    DcsPassthrough,
+    DcsIgnore,
+    OscString,
+    SosPmApcString,
+    /// After `ESC [ V`, which may be the start of the old `ESC [ V x D` redraw
+    VgatermRedraw,
+    /// After `ESC [ V x`
+    VgatermRedrawX,
+}
+
+/// C0 controls that get executed from (nearly) any state. CAN, SUB and ESC
+/// aren't in here, they're handled before we look at the state at all.
+#[inline]
+fn is_execute(c: char) -> bool {
+    matches!(c, '\u{00}'..='\u{17}' | '\u{19}' | '\u{1C}'..='\u{1F}')
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct Parser {
+    state: State,
+    params: Params,
+    /// The private marker (`<`, `=`, `>` or `?`) at the start of a CSI/DCS
+    marker: Option<char>,
+    intermediates: [char; MAX_INTERMEDIATES],
+    num_intermediates: usize,
+    /// Set when there were too many intermediates to keep. The sequence is
+    /// still consumed, but not acted on.
+    ignoring: bool,
+}
+
+impl Parser {
+    pub fn new() -> Parser {
+        Parser::default()
+    }
+
+    /// Feed every char of `s` through the parser, collecting what comes out
+    pub fn parse(&mut self, s: &str) -> Vec<OpChar> {
+        let mut out = Vec::new();
+        for c in s.chars() {
+            self.advance(c, |op| out.push(op));
+        }
+        out
+    }
+
+    /// Move the state machine along by one char, handing `emit` anything that
+    /// the char completed: a printable character, a control to execute, or an
+    /// escape sequence we understand.
+    pub fn advance(&mut self, c: char, mut emit: impl FnMut(OpChar)) {
+        use State::*;
+
+        // The rest of the old spelling of the redraw, `ESC [ V x D`. Anything
+        // else gives up on it, and is taken from the ground state.
+        match (self.state, c) {
+            (VgatermRedraw, 'x') => {
+                self.state = VgatermRedrawX;
+                return;
+            }
+            (VgatermRedrawX, 'D') => {
+                self.state = Ground;
+                emit(OpChar::Op(Op::Vgaterm(Vgaterm::Redraw)));
+                return;
+            }
+            (VgatermRedrawX, _) => {
+                self.state = Ground;
+                emit(OpChar::Char('x'));
+            }
+            (VgatermRedraw, _) => self.state = Ground,
+            _ => {}
+        }
+
+        // The transitions that apply no matter which state we're in
+        match c {
+            '\u{18}' | '\u{1A}' => {
+                // CAN and SUB abort whatever sequence we were in the middle of
+                self.state = Ground;
+                return;
+            }
+            ESC => {
+                self.clear();
+                self.state = Escape;
+                return;
+            }
+            '\u{90}' => {
+                self.clear();
+                self.state = DcsEntry;
+                return;
+            }
+            '\u{9B}' => {
+                self.clear();
+                self.state = CsiEntry;
+                return;
+            }
+            '\u{9C}' => {
+                // ST, string terminator
+                self.state = Ground;
+                return;
+            }
+            '\u{9D}' => {
+                self.state = OscString;
+                return;
+            }
+            '\u{98}' | '\u{9E}' | '\u{9F}' => {
+                self.state = SosPmApcString;
+                return;
+            }
+            '\u{80}'..='\u{9F}' => {
+                self.state = Ground;
+                emit(OpChar::Char(c));
+                return;
+            }
+            _ => {}
+        }
+
+        match self.state {
+            Ground | VgatermRedraw | VgatermRedrawX => emit(OpChar::Char(c)),
+
+            Escape => match c {
+                c if is_execute(c) => emit(OpChar::Char(c)),
+                '\u{7F}' => {}
+                '\u{20}'..='\u{2F}' => {
+                    self.collect(c);
+                    self.state = EscapeIntermediate;
+                }
+                '[' => {
+                    self.clear();
+                    self.state = CsiEntry;
+                }
+                ']' => self.state = OscString,
+                'P' => {
+                    self.clear();
+                    self.state = DcsEntry;
+                }
+                'X' | '^' | '_' => self.state = SosPmApcString,
+                '\u{30}'..='\u{7E}' => {
+                    self.esc_dispatch(c, &mut emit);
+                    self.state = Ground;
+                }
+                _ => {
+                    // Not something that can follow an ESC, so it wasn't an
+                    // escape sequence after all
+                    self.state = Ground;
+                    emit(OpChar::Char(c));
+                }
+            },
+
+            EscapeIntermediate => match c {
+                c if is_execute(c) => emit(OpChar::Char(c)),
+                '\u{20}'..='\u{2F}' => self.collect(c),
+                '\u{30}'..='\u{7E}' => {
+                    self.esc_dispatch(c, &mut emit);
+                    self.state = Ground;
+                }
+                '\u{7F}' => {}
+                _ => {
+                    self.state = Ground;
+                    emit(OpChar::Char(c));
+                }
+            },
+
+            CsiEntry => match c {
+                c if is_execute(c) => emit(OpChar::Char(c)),
+                '\u{7F}' => {}
+                '\u{20}'..='\u{2F}' => {
+                    self.collect(c);
+                    self.state = CsiIntermediate;
+                }
+                '0'..='9' | ':' | ';' => {
+                    self.params.push(c);
+                    self.state = CsiParam;
+                }
+                '<' | '=' | '>' | '?' => {
+                    self.marker = Some(c);
+                    self.state = CsiParam;
+                }
+                'V' => self.state = VgatermRedraw,
+                '\u{40}'..='\u{7E}' => {
+                    self.csi_dispatch(c, &mut emit);
+                    self.state = Ground;
+                }
+                _ => self.state = CsiIgnore,
+            },
+
+            CsiParam => match c {
+                c if is_execute(c) => emit(OpChar::Char(c)),
+                '\u{7F}' => {}
+                '0'..='9' | ':' | ';' => self.params.push(c),
+                '\u{20}'..='\u{2F}' => {
+                    self.collect(c);
+                    self.state = CsiIntermediate;
+                }
+                '\u{40}'..='\u{7E}' => {
+                    self.csi_dispatch(c, &mut emit);
+                    self.state = Ground;
+                }
+                _ => self.state = CsiIgnore,
+            },
+
+            CsiIntermediate => match c {
+                c if is_execute(c) => emit(OpChar::Char(c)),
+                '\u{7F}' => {}
+                '\u{20}'..='\u{2F}' => self.collect(c),
+                '\u{40}'..='\u{7E}' => {
+                    self.csi_dispatch(c, &mut emit);
+                    self.state = Ground;
+                }
+                _ => self.state = CsiIgnore,
+            },
+
+            CsiIgnore => match c {
+                c if is_execute(c) => emit(OpChar::Char(c)),
+                '\u{40}'..='\u{7E}' => self.state = Ground,
+                _ => {}
+            },
+
+            DcsEntry => match c {
+                '\u{20}'..='\u{2F}' => {
+                    self.collect(c);
+                    self.state = DcsIntermediate;
+                }
+                '0'..='9' | ';' => {
+                    self.params.push(c);
+                    self.state = DcsParam;
+                }
+                '<' | '=' | '>' | '?' => {
+                    self.marker = Some(c);
+                    self.state = DcsParam;
+                }
+                '\u{40}'..='\u{7E}' => self.state = DcsPassthrough,
+                ':' => self.state = DcsIgnore,
+                _ => {}
+            },
+
+            DcsParam => match c {
+                '0'..='9' | ';' => self.params.push(c),
+                '\u{20}'..='\u{2F}' => {
+                    self.collect(c);
+                    self.state = DcsIntermediate;
+                }
+                '\u{40}'..='\u{7E}' => self.state = DcsPassthrough,
+                ':' | '<' | '=' | '>' | '?' => self.state = DcsIgnore,
+                _ => {}
+            },
+
+            DcsIntermediate => match c {
+                '\u{20}'..='\u{2F}' => self.collect(c),
+                '\u{30}'..='\u{3F}' => self.state = DcsIgnore,
+                '\u{40}'..='\u{7E}' => self.state = DcsPassthrough,
+                _ => {}
+            },
+
+            // We don't implement any device control strings, so their contents
+            // are dropped until the ST (or ESC \) that ends them
+            DcsPassthrough | DcsIgnore | SosPmApcString => {}
+
+            OscString => {
+                // xterm also accepts BEL as the end of an OSC
+                if c == '\u{07}' {
+                    self.state = Ground;
+                }
+            }
+        }
+    }
+
+    fn clear(&mut self) {
+        self.params.clear();
+        self.marker = None;
+        self.num_intermediates = 0;
+        self.ignoring = false;
+    }
+
+    fn collect(&mut self, c: char) {
+        if self.num_intermediates < MAX_INTERMEDIATES {
+            self.intermediates[self.num_intermediates] = c;
+            self.num_intermediates += 1;
+        } else {
+            self.ignoring = true;
+        }
+    }
+
+    fn intermediates(&self) -> &[char] {
+        &self.intermediates[..self.num_intermediates]
+    }
+
+    fn esc_dispatch(&mut self, c: char, emit: &mut impl FnMut(OpChar)) {
+        if self.ignoring {
+            return;
+        }
+
+        let op = match (self.intermediates(), c) {
+            ([], '7') => Op::SaveCursorPos,
+            ([], '8') => Op::RestoreCursorPos,
+            ([], 'D') => Op::Index,
+            ([], 'M') => Op::ReverseIndex,
+            ([], 'H') => Op::SetTabStop,
+            ([g @ ('(' | ')')], set @ ('0' | 'B')) => Op::DesignateCharset {
+                g: usize::from(*g == ')'),
+                charset: match set {
+                    '0' => Charset::DecSpecialGraphics,
+                    _ => Charset::Ascii,
+                },
+            },
+            _ => return,
+        };
+        emit(OpChar::Op(op));
+    }
+
+    fn csi_dispatch(&mut self, c: char, emit: &mut impl FnMut(OpChar)) {
+        if self.ignoring {
+            return;
+        }
+
+        let p = &self.params;
+        let n = |default: u16| p.get_or(0, default) as isize;
+
+        let op = match (self.marker, self.intermediates(), c) {
+            (None, [], 'H' | 'f') => Op::MoveCursorAbs {
+                // Incoming sequence will be 1 index, so subtract 1 to get to 0 index representation
+                x: p.get_or(1, 1) as usize - 1,
+                y: p.get_or(0, 1) as usize - 1,
+            },
+            (None, [], 'A') => Op::MoveCursorDelta { dx: 0, dy: -n(1) },
+            (None, [], 'B') => Op::MoveCursorDelta { dx: 0, dy: n(1) },
+            (None, [], 'C') => Op::MoveCursorDelta { dx: n(1), dy: 0 },
+            (None, [], 'D') => Op::MoveCursorDelta { dx: -n(1), dy: 0 },
+            (None, [], 'E') => Op::MoveCursorBeginningAndLine { dy: n(1) },
+            (None, [], 'F') => Op::MoveCursorBeginningAndLine { dy: -n(1) },
+            (None, [], 'G') => Op::MoveCursorAbsCol {
+                x: p.get_or(0, 1) as usize - 1,
+            },
+            (None, [], 'I') => Op::TabForward { n: n(1) as usize },
+            (None, [], 'Z') => Op::TabBackward { n: n(1) as usize },
+            (None, [], 'g') => match p.get(0) {
+                0 => Op::ClearTabStop,
+                3 => Op::ClearTabStops,
+                _ => return,
+            },
+            (None, [], 'S') => Op::Scroll { delta: n(1) },
+            (None, [], 'T') => Op::Scroll { delta: -n(1) },
+            (None, [], 'r') => Op::SetScrollRegion {
+                // 1 indexed, with 0 or nothing meaning the edge of the screen
+                top: (p.get(0) as usize).checked_sub(1),
+                bottom: (p.get(1) as usize).checked_sub(1),
+            },
+            (None, [], 'n') if p.get(0) == 6 => Op::RequstCursorPos,
+            (None, [' '], 'q') => {
+                let shape = match p.get(0) {
+                    0..=2 => CursorShape::Block,
+                    3 | 4 => CursorShape::Underline,
+                    5 | 6 => CursorShape::Bar,
+                    _ => return,
+                };
+                // The odd ones blink, and so does 0, the terminal's default
+                Op::SetCursorStyle {
+                    shape,
+                    blinking: p.get(0) == 0 || p.get(0) % 2 == 1,
+                }
+            }
+            (None, [], 's') => Op::SaveCursorPos,
+            (None, [], 'u') => Op::RestoreCursorPos,
+            (None, [], '~') if p.get(0) == 3 => Op::InPlaceDelete,
+            (None, [], 'J') => match erase_mode(p.get(0)) {
+                Some(mode) => Op::EraseScreen(mode),
+                None => return,
+            },
+            (None, [], 'K') => match erase_mode(p.get(0)) {
+                Some(mode) => Op::EraseLine(mode),
+                None => return,
+            },
+            (None, [], 'L') => Op::InsertLines { n: n(1) as usize },
+            (None, [], 'M') => Op::DeleteLines { n: n(1) as usize },
+            (None, [], '@') => Op::InsertChars { n: n(1) as usize },
+            (None, [], 'P') => Op::DeleteChars { n: n(1) as usize },
+            (None, [], 'X') => Op::EraseChars { n: n(1) as usize },
+            (None, [], 'm') => {
+                select_graphic_rendition(p, emit);
+                return;
+            }
+            (Some('?'), [], 'h' | 'l') => {
+                // Each parameter is a separate mode, as in `ESC [ ? 1049 ; 25 h`
+                for mode in p.groups() {
+                    let mode = mode[0];
+                    emit(OpChar::Op(if c == 'h' {
+                        Op::DecPrivateSet(mode)
+                    } else {
+                        Op::DecPrivateReset(mode)
+                    }));
+                }
+                return;
+            }
+            (Some('>'), [], 'V') => match p.get(0) {
+                0 => Op::Vgaterm(Vgaterm::Redraw),
+                1 => Op::Vgaterm(Vgaterm::DebugDump),
+                _ => return,
+            },
+            (Some('>'), [], 'F') => Op::Vgaterm(Vgaterm::SelectFont(p.get(0) as usize)),
+            _ => return,
+        };
+        emit(OpChar::Op(op));
+    }
+}
+
+fn erase_mode(n: u16) -> Option<EraseMode> {
+    match n {
+        0 => Some(EraseMode::FromCursor),
+        1 => Some(EraseMode::ToCursor),
+        2 => Some(EraseMode::All),
+        _ => None,
+    }
+}
+
+/// ESC [ <n>;<n>;... m
+///
+/// Each parameter comes out as its own [`Op::TextOp`]. Unrecognized parameters
+/// are skipped, the rest of the sequence still applies
+fn select_graphic_rendition(params: &Params, emit: &mut impl FnMut(OpChar)) {
+    use SetUnset::*;
+
+    if params.is_empty() {
+        emit(OpChar::Op(Op::TextOp(TextOp::ResetColors)));
+    }
+
+    let mut groups = params.groups();
+    while let Some(group) = groups.next() {
+        let op = match group[0] {
+            0 => TextOp::ResetColors,
+            1 => TextOp::SetTextMode(Set, Style::Bold),
+            2 => TextOp::SetTextMode(Set, Style::Dim),
+            22 => TextOp::SetTextMode(Unset, Style::Bold),
+            3 => TextOp::SetTextMode(Set, Style::Italic),
+            23 => TextOp::SetTextMode(Unset, Style::Italic),
+            4 => TextOp::SetTextMode(Set, Style::Underline),
+            24 => TextOp::SetTextMode(Unset, Style::Underline),
+            5 => TextOp::SetTextMode(Set, Style::Blinking),
+            25 => TextOp::SetTextMode(Unset, Style::Blinking),
+            7 => TextOp::SetTextMode(Set, Style::Inverse),
+            27 => TextOp::SetTextMode(Unset, Style::Inverse),
+            9 => TextOp::SetTextMode(Set, Style::Strike),
+            29 => TextOp::SetTextMode(Unset, Style::Strike),
+            fg @ (30..=37 | 90..=97) => TextOp::SetFGBasic { fg: fg as u8 },
+            bg @ (40..=47 | 100..=107) => TextOp::SetBGBasic { bg: bg as u8 },
+            sel @ (38 | 48) => match (sel, extended_color(group, &mut groups)) {
+                (38, Some(ExtendedColor::Indexed(fg))) => TextOp::SetFGColor256 { fg },
+                (_, Some(ExtendedColor::Indexed(bg))) => TextOp::SetBGColor256 { bg },
+                (38, Some(ExtendedColor::Rgb(r, g, b))) => TextOp::SetFGColorRgb { r, g, b },
+                (_, Some(ExtendedColor::Rgb(r, g, b))) => TextOp::SetBGColorRgb { r, g, b },
+                (_, None) => continue,
+            },
+            _ => continue,
+        };
+        emit(OpChar::Op(Op::TextOp(op)));
+    }
+}
+
+enum ExtendedColor {
+    Indexed(u8),
+    Rgb(u8, u8, u8),
+}
+
+/// The color given after a 38 or 48, either as its sub-parameters (`38:5:<c>`)
+/// or as the parameters that follow it (`38;5;<c>`)
+fn extended_color(group: &[u16], groups: &mut Groups) -> Option<ExtendedColor> {
+    let byte = |v: u16| u8::try_from(v).ok();
+    if group.len() > 1 {
+        // The color space id in `38:2:<id>:<r>:<g>:<b>` is often left out
+        // entirely, rather than just left empty
+        return match group[1..] {
+            [5, c] => Some(ExtendedColor::Indexed(byte(c)?)),
+            [2, _, r, g, b] | [2, r, g, b] => {
+                Some(ExtendedColor::Rgb(byte(r)?, byte(g)?, byte(b)?))
+            }
+            _ => None,
+        };
+    }
+    match groups.next()? {
+        [5] => Some(ExtendedColor::Indexed(byte(groups.next()?[0])?)),
+        [2] => {
+            // Take all three before checking any of them, so an out of range
+            // channel doesn't leave the rest to be read as SGRs of their own
+            let mut channel = || groups.next().map(|v| byte(v[0]));
+            let (r, g, b) = (channel()?, channel()?, channel()?);
+            Some(ExtendedColor::Rgb(r?, g?, b?))
+        }
+        _ => None,
+    }
+}
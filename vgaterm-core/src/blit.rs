@@ -0,0 +1,228 @@
+//! Drawing text cells straight into a frame of color bytes, rather than
+//! through embedded-graphics' `Text`, which costs a `String`, a style and a
+//! pixel iterator for every cell.
+//!
+//! Each glyph is read out of its font once, as a byte per row with a bit per
+//! pixel. Drawing a row then takes one lookup to spread those bits into byte
+//! lanes, a mask to pick the foreground or background color for each lane,
+//! and as few word wide stores as the row's alignment allows.
+
+use alloc::{vec, vec::Vec};
+use core::convert::Infallible;
+
+use embedded_graphics::{
+    image::Image, mono_font::MonoFont, pixelcolor::BinaryColor, prelude::*, Pixel,
+};
+
+use crate::{color::Rgb3, framebuffer, text::Font};
+
+/// The widest glyph a row byte has room for
+pub const MAX_WIDTH: u32 = 8;
+
+/// How many pixels thick the underline and bar cursors are
+pub const CURSOR_THICKNESS: u32 = 2;
+
+/// `LANES[bits]` has byte `i` all ones where bit `i` of `bits` is set
+static LANES: [u64; 256] = lanes();
+
+const fn lanes() -> [u64; 256] {
+    let mut lanes = [0; 256];
+    let mut bits = 0;
+    while bits < 256 {
+        let mut lane = 0;
+        while lane < 8 {
+            if bits & (1 << lane) != 0 {
+                lanes[bits] |= 0xFF << (lane * 8);
+            }
+            lane += 1;
+        }
+        bits += 1;
+    }
+    lanes
+}
+
+/// Every glyph of a font, a byte per row, with the leftmost pixel in bit 0
+pub struct Glyphs {
+    height: usize,
+    rows: Vec<u8>,
+}
+
+impl Glyphs {
+    /// Read the glyphs out of `font`, if they're narrow enough
+    pub fn new(font: &MonoFont) -> Option<Glyphs> {
+        let size = font.character_size;
+        if size.width == 0 || size.width > MAX_WIDTH {
+            return None;
+        }
+        let image = font.image.size();
+        let per_row = image.width / size.width;
+        let count = per_row * (image.height / size.height);
+        let mut reader = Reader {
+            glyph: size,
+            per_row,
+            rows: vec![0; (count * size.height) as usize],
+        };
+        let _ = Image::new(&font.image, Point::zero()).draw(&mut reader);
+        Some(Glyphs {
+            height: size.height as usize,
+            rows: reader.rows,
+        })
+    }
+
+    fn row(&self, glyph: usize, row: usize) -> u8 {
+        self.rows
+            .get(glyph * self.height + row)
+            .copied()
+            .unwrap_or_default()
+    }
+}
+
+/// Collects a font image's pixels into glyph rows
+struct Reader {
+    glyph: Size,
+    per_row: u32,
+    rows: Vec<u8>,
+}
+
+impl OriginDimensions for Reader {
+    fn size(&self) -> Size {
+        Size::new(
+            self.glyph.width * self.per_row,
+            self.rows.len() as u32 / self.per_row,
+        )
+    }
+}
+
+impl DrawTarget for Reader {
+    type Color = BinaryColor;
+    type Error = Infallible;
+
+    fn draw_iter<I>(&mut self, pixels: I) -> Result<(), Self::Error>
+    where
+        I: IntoIterator<Item = Pixel<Self::Color>>,
+    {
+        let Size { width, height } = self.glyph;
+        for Pixel(p, color) in pixels {
+            if color.is_off() || p.x < 0 || p.y < 0 {
+                continue;
+            }
+            let (x, y) = (p.x as u32, p.y as u32);
+            let glyph = (y / height) * self.per_row + x / width;
+            let i = (glyph * height + y % height) as usize;
+            if let Some(row) = self.rows.get_mut(i) {
+                *row |= 1 << (x % width);
+            }
+        }
+        Ok(())
+    }
+}
+
+/// What to draw in a cell
+pub struct Cell {
+    /// The glyph index, or `None` for nothing but the background and
+    /// decorations
+    pub glyph: Option<usize>,
+    pub fore: Rgb3,
+    pub back: Rgb3,
+    pub underline: bool,
+    pub strikethrough: bool,
+    /// Drawn a second time a pixel over, for bold without a bright color
+    pub overstruck: bool,
+    /// With the top half of the glyph a pixel over
+    pub italic: bool,
+    /// With the cursor's line along the bottom of the cell
+    pub underline_cursor: bool,
+    /// With the cursor's line down the left side of the cell
+    pub bar_cursor: bool,
+}
+
+/// Draw `cell` at `line`, `col` of `font`'s grid into `frame`, which is laid
+/// out like `video::BUFFER` with the picture starting at row `top`
+pub fn draw(
+    frame: &mut [u8],
+    top: usize,
+    font: &Font,
+    glyphs: &Glyphs,
+    line: usize,
+    col: usize,
+    cell: &Cell,
+) {
+    let mono = font.glyphs;
+    let (cell_width, cell_height) = (font.cell.width as usize, font.cell.height as usize);
+    let glyph_height = mono.character_size.height as i32;
+    let offset = font.glyph_offset();
+    let x = font.origin.x as usize + col * cell_width;
+    let y = font.origin.y as usize + line * cell_height;
+
+    // Decorations are placed from the top of the glyph, like embedded-graphics
+    // does, but kept inside the cell
+    let decoration = |d: embedded_graphics::mono_font::DecorationDimensions| {
+        let top = (offset.y + d.offset as i32).min(cell_height as i32 - d.height as i32);
+        top..top + d.height as i32
+    };
+    let underline = decoration(mono.underline);
+    let strikethrough = decoration(mono.strikethrough);
+
+    let cell_mask = (1u16 << cell_width) - 1;
+    let fore = u64::from_ne_bytes([cell.fore.to_byte(); 8]);
+    let back = u64::from_ne_bytes([cell.back.to_byte(); 8]);
+
+    for row in 0..cell_height as i32 {
+        let glyph_row = row - offset.y;
+        let mut bits = match cell.glyph {
+            Some(glyph) if (0..glyph_height).contains(&glyph_row) => {
+                u16::from(glyphs.row(glyph, glyph_row as usize)) << offset.x
+            }
+            _ => 0,
+        };
+        if cell.overstruck {
+            bits |= bits << 1;
+        }
+        if cell.italic && (0..glyph_height / 2).contains(&glyph_row) {
+            bits <<= 1;
+        }
+        if (cell.underline && underline.contains(&row))
+            || (cell.strikethrough && strikethrough.contains(&row))
+        {
+            bits = cell_mask;
+        }
+        if cell.underline_cursor && row >= (cell_height as u32 - CURSOR_THICKNESS) as i32 {
+            bits = cell_mask;
+        }
+        if cell.bar_cursor {
+            bits |= (1 << CURSOR_THICKNESS) - 1;
+        }
+
+        let lanes = LANES[usize::from(bits & cell_mask)];
+        let start = framebuffer::index(top, x, y + row as usize);
+        if let Some(pixels) = frame.get_mut(start..start + cell_width) {
+            store(pixels, (fore & lanes) | (back & !lanes));
+        }
+    }
+}
+
+/// Write the low `dst.len()` bytes of `value` into `dst`, a word or half word
+/// at a time wherever the address lines up for it
+#[inline(always)]
+fn store(dst: &mut [u8], value: u64) {
+    let mut i = 0;
+    while i < dst.len() {
+        let rest = dst.len() - i;
+        let lane = value >> (i * 8);
+        let p = dst[i..].as_mut_ptr();
+        // SAFETY: each write stays within `dst`, and is only made at an
+        // address aligned for it
+        unsafe {
+            if rest >= 4 && p as usize & 3 == 0 {
+                p.cast::<u32>().write((lane as u32).to_le());
+                i += 4;
+            } else if rest >= 2 && p as usize & 1 == 0 {
+                p.cast::<u16>().write((lane as u16).to_le());
+                i += 2;
+            } else {
+                p.write(lane as u8);
+                i += 1;
+            }
+        }
+    }
+}
@@ -8,7 +8,7 @@ use embedded_graphics::{
 pub fn rgb_from_byte(color: u8) -> (u8, u8, u8) {
     let color: u16 = color as u16;
     let shifted = color << 1;
-    let highest = shifted >> 7;
+    let highest = shifted >> 8;
     let rgb = shifted + highest;
 
     let red3 = (rgb & 0b0_0000_0111) as u8;
@@ -65,7 +65,7 @@ pub fn byte_to_rgb3(b: u8) -> (u8, u8, u8) {
     )
 }
 
-pub fn color3_to_byte(color: u8) -> u8 {
+pub const fn color3_to_byte(color: u8) -> u8 {
     let color = color % 8;
     match color {
         0 => 0,
@@ -80,6 +80,20 @@ pub fn color3_to_byte(color: u8) -> u8 {
     }
 }
 
+/// The 3 bit level closest to an 8 bit one
+const fn nearest_color3(v: u8) -> u8 {
+    ((v as u16 * 7 + 127) / 255) as u8
+}
+
+/// How different two colors look, leaving out green as it's the same for
+/// both, scaled up by 256 to stay in integers
+const fn redmean_distance((r1, b1): (u8, u8), (r2, b2): (u8, u8)) -> u32 {
+    let mean = (r1 as u32 + r2 as u32) / 2;
+    let dr = r1.abs_diff(r2) as u32;
+    let db = b1.abs_diff(b2) as u32;
+    (512 + mean) * dr * dr + (767 - mean) * db * db
+}
+
 pub fn color2_to_color3(color2: u8) -> u8 {
     // Set to 2 bit value
     let color2 = color2 & 0b00000011;
@@ -126,11 +140,31 @@ impl Rgb3 {
         }
     }
 
+    /// The displayable color closest to a 24 bit one, going by the "redmean"
+    /// approximation of how different colors look.
+    ///
+    /// Green is independent, but red only has two bits of its own in the
+    /// color byte, with its lowest bit taken from the top bit of blue, so
+    /// those two are picked together.
     pub const fn from_rgb(r: u8, g: u8, b: u8) -> Rgb3 {
-        let r3 = r / 36;
-        let g3 = g / 36;
-        let b3 = b / 36;
-        Rgb3::new(r3, g3, b3)
+        let g3 = nearest_color3(g);
+
+        let mut best = Rgb3::new(0, g3, 0);
+        let mut best_distance = u32::MAX;
+        let mut b3 = 0;
+        while b3 < 8 {
+            let mut r3 = b3 >> 2;
+            while r3 < 8 {
+                let distance = redmean_distance((r, b), (color3_to_byte(r3), color3_to_byte(b3)));
+                if distance < best_distance {
+                    best = Rgb3::new(r3, g3, b3);
+                    best_distance = distance;
+                }
+                r3 += 2;
+            }
+            b3 += 1;
+        }
+        best
     }
 
     pub fn brightness(&self, b: u8) -> Rgb3 {
@@ -312,6 +346,18 @@ pub const BRIGHT_MAGENTA_BG: u8 = 105;
 pub const BRIGHT_CYAN_BG: u8 = 106;
 pub const BRIGHT_WHITE_BG: u8 = 107;
 
+/// The bright counterpart of one of the eight base colors, which is how xterm
+/// shows them in bold
+pub fn bright_variant(color: Rgb3) -> Option<Rgb3> {
+    // Compare as bytes, as that's what colors have been through on their way
+    // into a cell
+    let byte = color.to_byte();
+    ANSI_BASE_LOW_COLORS
+        .iter()
+        .position(|c| c.to_byte() == byte)
+        .map(|i| ANSI_BASE_HIGH_COLORS[i])
+}
+
 pub fn ansi_base_color(fore: u8, back: u8) -> (Rgb3, Rgb3) {
     let fg = match fore {
         ANSI_BASE_FG_LOW_START..=ANSI_BASE_FG_LOW_STOP => {
@@ -351,19 +397,19 @@ pub fn ansi_256_color(color: u8) -> Rgb3 {
             cube_color -= green6 * 6;
             let blue6 = cube_color;
 
-            fn map_6_to_8(v6: u8) -> u8 {
+            // The levels xterm uses for each step of the cube
+            fn cube_level(v6: u8) -> u8 {
                 match v6 {
                     0 => 0,
-                    1 => 1,
-                    2 => 3,
-                    3 => 4,
-                    4 => 6,
-                    5 => 7,
-                    _ => 0,
+                    v => 55 + v * 40,
                 }
             }
-            Rgb3::new(map_6_to_8(red6), map_6_to_8(green6), map_6_to_8(blue6))
+            Rgb3::from_rgb(cube_level(red6), cube_level(green6), cube_level(blue6))
+        }
+        232..=255 => {
+            // A ramp of greys, from 8 up by 10s
+            let grey = 8 + (color - 232) * 10;
+            Rgb3::from_rgb(grey, grey, grey)
         }
-        232..=255 => Rgb3::new(color / 24, color / 24, color / 24),
     }
 }
@@ -0,0 +1,1196 @@
+use core::{cmp::Ordering, ops::Range};
+
+use alloc::{
+    string::{String, ToString},
+    vec,
+    vec::Vec,
+};
+use embedded_graphics::{
+    mono_font::{MonoFont, MonoTextStyle, MonoTextStyleBuilder},
+    prelude::*,
+    primitives::{PrimitiveStyle, Rectangle},
+    text::{DecorationColor, Text},
+};
+use fixedbitset::FixedBitSet;
+
+use crate::{
+    blit::{self, Glyphs},
+    color::{self, Rgb3},
+    framebuffer::{self, Frame},
+    scrollback::{self, Scrollback},
+    text::{self, Font},
+};
+
+/// Set in the top byte of a `Character`'s code point on the left cell of a
+/// double width character
+const WIDE: u8 = 0x80;
+/// Set in the top byte of a `Character`'s code point on the cell covered by
+/// the right half of a double width character
+const WIDE_RIGHT: u8 = 0x40;
+/// The bits of the top byte that belong to the code point
+const CODE_POINT_HIGH: u8 = 0x1F;
+
+#[derive(Debug, Clone, Copy)]
+pub struct Character {
+    /// The code point, little endian. Code points only go up to 21 bits, so
+    /// the top of the last byte is left for `WIDE` and `WIDE_RIGHT`.
+    character: [u8; 3],
+    pub color: CharColor,
+}
+
+fn pack(ch: char) -> [u8; 3] {
+    let [a, b, c, _] = (ch as u32).to_le_bytes();
+    [a, b, c]
+}
+
+impl Character {
+    pub fn new(ch: char) -> Character {
+        Character {
+            character: pack(ch),
+            color: CharColor::default(),
+        }
+    }
+
+    pub fn new_with_color(
+        ch: char,
+        fore: Rgb3,
+        back: Rgb3,
+        decorations: &[Decoration],
+    ) -> Character {
+        let charcolor = CharColor::new(fore, back).with_decorations(decorations);
+        Character {
+            character: pack(ch),
+            color: charcolor,
+        }
+    }
+
+    pub fn with_char(&mut self, ch: char) -> Character {
+        self.character = pack(ch);
+        Character {
+            character: self.character,
+            color: self.color,
+        }
+    }
+
+    pub fn with_fore(self, color: Rgb3) -> Character {
+        Character {
+            character: self.character,
+            color: self.color.with_foreground(color),
+        }
+    }
+
+    pub fn with_back(self, color: Rgb3) -> Character {
+        Character {
+            character: self.character,
+            color: self.color.with_background(color),
+        }
+    }
+
+    /// The left cell of a double width character
+    pub fn wide(mut self) -> Character {
+        self.character[2] |= WIDE;
+        self
+    }
+
+    /// The cell covered by the right half of a double width character
+    pub fn wide_right(mut self) -> Character {
+        self.character[2] |= WIDE_RIGHT;
+        self
+    }
+
+    pub fn is_wide(&self) -> bool {
+        self.character[2] & WIDE != 0
+    }
+
+    pub fn is_wide_right(&self) -> bool {
+        self.character[2] & WIDE_RIGHT != 0
+    }
+
+    /// Whether this is a plain space on the default background, like the
+    /// screen starts out with
+    pub fn is_blank(&self) -> bool {
+        let blank = Character::default();
+        self.character == blank.character
+            && self.color.back == blank.color.back
+            && self.color.decoration == 0
+    }
+
+    pub fn char(&self) -> char {
+        let [a, b, c] = self.character;
+        char::from_u32(u32::from_le_bytes([a, b, c & CODE_POINT_HIGH, 0])).unwrap_or(' ')
+    }
+
+    /// Whether this is bold in a color without a bright variant, so it has to
+    /// be shown by thickening the glyph instead
+    pub fn overstruck(&self) -> bool {
+        self.color.bold() && color::bright_variant(self.color.foreground()).is_none()
+    }
+
+    /// The colors the glyph and the background behind it are drawn in, once
+    /// bold, dim and inverse have had their say
+    pub fn colors(&self) -> (Rgb3, Rgb3) {
+        let mut fore = self.color.foreground();
+        if self.color.bold() {
+            fore = color::bright_variant(fore).unwrap_or(fore);
+        }
+        if self.color.dim() {
+            fore = fore.brightness(DIM_BRIGHTNESS);
+        }
+
+        if self.color.inverse() {
+            (self.color.background(), fore)
+        } else {
+            (fore, self.color.background())
+        }
+    }
+
+    pub fn text_and_style<'a>(&self, font: &'a MonoFont<'a>) -> (String, MonoTextStyle<'a, Rgb3>) {
+        // The right half of a double width character is drawn with the left
+        let text = if self.is_wide_right() {
+            String::from(" ")
+        } else {
+            self.char().to_string()
+        };
+
+        let (fore, back) = self.colors();
+        let mut style_builder = MonoTextStyleBuilder::new()
+            .text_color(fore)
+            .background_color(back)
+            .font(font);
+
+        if self.color.strikethrough() {
+            style_builder = style_builder.strikethrough();
+        }
+
+        if self.color.underline() {
+            style_builder = style_builder.underline();
+        }
+
+        let style = style_builder.build();
+
+        (text, style)
+    }
+}
+
+impl Default for Character {
+    fn default() -> Self {
+        Character {
+            character: pack(' '),
+            color: CharColor::default(),
+        }
+    }
+}
+
+/// The foreground and background as hardware color bytes (see
+/// [`Rgb3::to_byte`]), and a byte of [`Decoration`] bits:
+/// Inverse: 7, Underline: 6, Strike: 5, Blink: 4, Italic: 3, Dim: 2, Bold: 1,
+/// with bit 0 unused
+/// IUSB|IDB-
+#[derive(Debug, Clone, Copy)]
+pub struct CharColor {
+    fore: u8,
+    back: u8,
+    decoration: u8,
+}
+
+impl CharColor {
+    pub fn new(foreground: Rgb3, background: Rgb3) -> CharColor {
+        CharColor {
+            fore: foreground.to_byte(),
+            back: background.to_byte(),
+            decoration: 0,
+        }
+    }
+
+    pub fn foreground(&self) -> Rgb3 {
+        // let r = ((self.0 & 0b00000011) << 1) as u8;
+        // let g = ((self.0 & 0b00001100) >> 1) as u8;
+        // let b = ((self.0 & 0b00110000) >> 3) as u8;
+        let (r, g, b) = color::byte_to_rgb3(self.fore);
+        Rgb3::new(r, g, b)
+    }
+
+    pub fn background(&self) -> Rgb3 {
+        // let r = ((self.0 & 0b11000000) >> 5) as u8;
+        // let g = ((self.0 & 0b0000_0011_0000_0000) >> 7) as u8;
+        // let b = ((self.0 & 0b0000_1100_0000_0000) >> 9) as u8;
+        let (r, g, b) = color::byte_to_rgb3(self.back);
+        Rgb3::new(r, g, b)
+    }
+
+    pub fn inverse(&self) -> bool {
+        self.decoration & Decoration::Inverse.bit() != 0
+    }
+
+    pub fn underline(&self) -> bool {
+        self.decoration & Decoration::Underline.bit() != 0
+    }
+
+    pub fn strikethrough(&self) -> bool {
+        self.decoration & Decoration::Strikethrough.bit() != 0
+    }
+
+    pub fn bold(&self) -> bool {
+        self.decoration & Decoration::Bold.bit() != 0
+    }
+
+    pub fn dim(&self) -> bool {
+        self.decoration & Decoration::Dim.bit() != 0
+    }
+
+    pub fn italic(&self) -> bool {
+        self.decoration & Decoration::Italic.bit() != 0
+    }
+
+    pub fn blink(&self) -> bool {
+        self.decoration & Decoration::Blink.bit() != 0
+    }
+
+    pub fn with_foreground(self, color: Rgb3) -> CharColor {
+        // let (r2, g2, b2) = color.rgb2();
+        // let c = ((r2 + g2) << (2 + b2) << 4) as u16;
+        CharColor {
+            fore: color.to_byte(),
+            back: self.back,
+            decoration: self.decoration
+        }
+    }
+
+    pub fn with_background(self, color: Rgb3) -> CharColor {
+        // let (r2, g2, b2) = color.rgb2();
+        // // Background starts at bit 6
+        // let c = (((r2 + g2) << (2 + b2) << 4) as u16) << 6;
+
+        CharColor {
+            fore: self.fore,
+            back: color.to_byte(),
+            decoration: self.decoration,
+        }
+    }
+
+    pub fn with_decorations(&mut self, decs: &[Decoration]) -> CharColor {
+        let mut dec_value = 0;
+        for d in decs {
+            dec_value |= d.bit();
+        }
+        self.decoration = dec_value;
+        *self
+    }
+
+    pub fn invert_colors(&mut self) -> CharColor {
+        self.decoration ^= Decoration::Inverse.bit();
+        *self
+    }
+
+    pub fn set_inverted(&mut self) -> CharColor {
+        if self.decoration & Decoration::Inverse.bit() == 0 {
+            self.decoration |= Decoration::Inverse.bit();
+        }
+        *self
+    }
+
+    pub fn reset_inverted(&mut self) -> CharColor {
+        if self.decoration & Decoration::Inverse.bit() != 0 {
+            self.decoration &= !Decoration::Inverse.bit();
+        }
+        *self
+    }
+}
+
+impl Default for CharColor {
+    fn default() -> CharColor {
+        CharColor::new(Rgb3::new(6, 6, 6), Rgb3::BLACK)
+    }
+}
+
+/// How bright dim text is, in eighths of its color
+const DIM_BRIGHTNESS: u8 = 5;
+
+/// How the cursor is drawn over the cell it's on
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum CursorShape {
+    /// The whole cell, in inverse
+    #[default]
+    Block,
+    /// A line along the bottom of the cell
+    Underline,
+    /// A line down the left side of the cell
+    Bar,
+}
+
+#[derive(Debug, Clone, PartialEq, PartialOrd)]
+pub enum Decoration {
+    Bold,
+    Dim,
+    Italic,
+    Blink,
+    Strikethrough,
+    Underline,
+    Inverse,
+}
+
+impl Flag for Decoration {
+    fn bit(&self) -> u8 {
+        match self {
+            Decoration::Bold => 1 << 1,
+            Decoration::Dim => 1 << 2,
+            Decoration::Italic => 1 << 3,
+            Decoration::Blink => 1 << 4,
+            Decoration::Strikethrough => 1 << 5,
+            Decoration::Underline => 1 << 6,
+            Decoration::Inverse => 1 << 7,
+        }
+    }
+}
+
+impl<T: Flag> Flag for Option<T> {
+    fn bit(&self) -> u8 {
+        match self {
+            Some(f) => f.bit(),
+            None => 0,
+        }
+    }
+}
+
+pub trait Flag {
+    fn bit(&self) -> u8;
+}
+
+#[derive(Clone)]
+pub struct ColorDecs {
+    pub fore: Rgb3,
+    pub back: Rgb3,
+    pub decs: Vec<Decoration>,
+}
+
+impl ColorDecs {
+    pub fn set(&mut self, dec: Decoration) {
+        if !self.decs.contains(&dec) {
+            self.decs.push(dec);
+        }
+    }
+
+    pub fn unset(&mut self, dec: Decoration) {
+        self.decs.retain(|d| *d != dec);
+    }
+}
+
+pub struct TextDisplay {
+    font: &'static Font,
+    columns: usize,
+    rows: usize,
+    /// `rows` lines of `columns` cells each, in a ring starting at line `top`
+    buffer: Vec<Character>,
+    /// Which cells need drawing, a bitmap of columns for each line of the
+    /// ring, so that lines with nothing to draw are passed over a word at a
+    /// time
+    dirty: Vec<FixedBitSet>,
+    /// Which lines of the ring have anything set in `dirty`
+    dirty_lines: FixedBitSet,
+    /// How many cells are set in `dirty`
+    num_dirty: usize,
+    top: usize,
+    pub current_color: ColorDecs,
+    pub scrollback: Scrollback,
+    /// How many lines back through the scrollback the view is paged
+    view_offset: usize,
+    /// Whether the lines of scrollback in view still need drawing
+    history_dirty: bool,
+    /// Whether blinking text is showing, for this phase of the blink
+    blink_visible: bool,
+    /// Set when the grid has changed shape, so whatever is drawn next has to
+    /// start from a blank screen
+    repaint: bool,
+    /// Lines the screen has scrolled up (down, if negative) since it was last
+    /// drawn, which the target catches up on by moving its pixels rather
+    /// than having every cell drawn again
+    scrolled: isize,
+    /// The font's glyphs read out for [`blit`], if they're narrow enough
+    glyphs: Option<Glyphs>,
+    renderer: Renderer,
+    /// The line of the ring and the column the cursor is drawn over, and its
+    /// shape. It's only drawn over the cell, and never written into it.
+    cursor: Option<(usize, usize, CursorShape)>,
+}
+
+/// How text cells get drawn
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum Renderer {
+    /// Written straight into the target's pixels by [`blit`], where the
+    /// target and the font allow it
+    #[default]
+    Glyphs,
+    /// Through embedded-graphics' `Text`, which works on any target
+    Text,
+}
+
+impl TextDisplay {
+    pub fn new() -> TextDisplay {
+        TextDisplay::with_font(&text::FONT_6X12)
+    }
+
+    /// A display with its grid laid out for `font`
+    pub fn with_font(font: &'static Font) -> TextDisplay {
+        TextDisplay::with_scrollback(font, scrollback::DEFAULT_CELLS)
+    }
+
+    /// A display keeping up to `max_cells` cells of scrollback
+    pub fn with_scrollback(font: &'static Font, max_cells: usize) -> TextDisplay {
+        let (fore, back) = color::ansi_base_color(color::WHITE_FG, color::BLACK_BG);
+        TextDisplay {
+            font,
+            columns: font.columns,
+            rows: font.rows,
+            buffer: vec![Character::default(); font.columns * font.rows],
+            dirty: vec![FixedBitSet::with_capacity(font.columns); font.rows],
+            dirty_lines: FixedBitSet::with_capacity(font.rows),
+            num_dirty: 0,
+            top: 0,
+            current_color: ColorDecs {
+                fore,
+                back,
+                decs: Vec::new(),
+            },
+            scrollback: Scrollback::new(max_cells),
+            view_offset: 0,
+            history_dirty: false,
+            blink_visible: true,
+            repaint: false,
+            scrolled: 0,
+            glyphs: glyph_table(font),
+            renderer: Renderer::default(),
+            cursor: None,
+        }
+    }
+
+    pub fn font(&self) -> &'static Font {
+        self.font
+    }
+
+    pub fn columns(&self) -> usize {
+        self.columns
+    }
+
+    pub fn rows(&self) -> usize {
+        self.rows
+    }
+
+    pub fn renderer(&self) -> Renderer {
+        self.renderer
+    }
+
+    /// Draw with `renderer` from here on. Only what's drawn after changes.
+    pub fn set_renderer(&mut self, renderer: Renderer) {
+        self.renderer = renderer;
+    }
+
+    /// Lay the grid out again for `font`, which starts it off blank. The
+    /// scrollback stays, and lines of another width are shown cut off or
+    /// padded out.
+    pub fn set_font(&mut self, font: &'static Font) {
+        self.font = font;
+        self.columns = font.columns;
+        self.rows = font.rows;
+        // Let go of the old grid before making room for the new one
+        self.buffer = Vec::new();
+        self.glyphs = None;
+        self.buffer
+            .resize(font.columns * font.rows, Character::default());
+        self.glyphs = glyph_table(font);
+        self.dirty = vec![FixedBitSet::with_capacity(font.columns); font.rows];
+        self.dirty_lines = FixedBitSet::with_capacity(font.rows);
+        self.top = 0;
+        self.view_offset = 0;
+        self.history_dirty = false;
+        self.repaint = true;
+        self.scrolled = 0;
+        self.cursor = None;
+        self.dirty_all();
+    }
+
+    fn real_row(&self, line: usize) -> usize {
+        (self.top + line) % self.rows
+    }
+
+    fn real_index(&self, line: usize, col: usize) -> usize {
+        self.real_row(line) * self.columns + col
+    }
+
+    /// Show the cursor over the cell at `line`, `col` of the screen, or not
+    /// at all, marking the cell it leaves and the one it lands on for drawing
+    pub fn set_cursor(&mut self, cursor: Option<(usize, usize, CursorShape)>) {
+        let cursor = cursor.map(|(line, col, shape)| (self.real_row(line), col, shape));
+        if cursor == self.cursor {
+            return;
+        }
+        for (real_row, col, _) in [self.cursor, cursor].into_iter().flatten() {
+            self.mark(real_row, col);
+        }
+        self.cursor = cursor;
+    }
+
+    /// The shape of the cursor, if it's over the cell at `line`, `col`
+    fn cursor_at(&self, line: usize, col: usize) -> Option<CursorShape> {
+        let (real_row, cursor_col, shape) = self.cursor?;
+        (real_row == self.real_row(line) && cursor_col == col).then_some(shape)
+    }
+
+    /// Mark the cell at `col` of the ring's line `real_row` for drawing
+    fn mark(&mut self, real_row: usize, col: usize) {
+        if !self.dirty[real_row].put(col) {
+            self.num_dirty += 1;
+        }
+        self.dirty_lines.insert(real_row);
+    }
+
+    #[inline(always)]
+    pub fn read_char(&self, line: usize, col: usize) -> Character {
+        self.buffer[self.real_index(line, col)]
+    }
+
+    #[inline(always)]
+    pub fn write_char(&mut self, line: usize, col: usize, c: Character) {
+        let i = self.real_index(line, col);
+        self.buffer[i] = c;
+        self.mark(self.real_row(line), col);
+    }
+
+    #[inline(always)]
+    pub fn write(&mut self, line: usize, col: usize, c: char) {
+        self.split_wide(line, col);
+        let ch = self.colored(c);
+        self.write_char(line, col, ch)
+    }
+
+    /// Write a double width character across `col` and the column after it,
+    /// which has to be on screen too
+    pub fn write_wide(&mut self, line: usize, col: usize, c: char) {
+        self.split_wide(line, col);
+        self.split_wide(line, col + 1);
+        let ch = self.colored(c);
+        self.write_char(line, col, ch.wide());
+        self.write_char(line, col + 1, ch.wide_right());
+    }
+
+    fn colored(&self, c: char) -> Character {
+        Character::new_with_color(
+            c,
+            self.current_color.fore,
+            self.current_color.back,
+            &self.current_color.decs,
+        )
+    }
+
+    /// Blank the other half of a double width character at `line`, `col`,
+    /// before half of it gets written over
+    fn split_wide(&mut self, line: usize, col: usize) {
+        let ch = self.read_char(line, col);
+        let other = if ch.is_wide() {
+            col + 1
+        } else if ch.is_wide_right() && col > 0 {
+            col - 1
+        } else {
+            return;
+        };
+        if other < self.columns {
+            let blank = self.read_char(line, other).with_char(' ');
+            self.write_char(line, other, blank);
+        }
+    }
+
+    pub fn write_text(&mut self, start_line: usize, start_column: usize, text: &str) {
+        let start_line = start_line % self.rows;
+        let start_column = start_column % self.columns;
+        // We know now that the start cell is within the frame
+        let mut row = start_line;
+        let mut col = start_column;
+        for (_, c) in text.chars().enumerate() {
+            self.write(row, col, c);
+            col += 1;
+            if col == self.columns {
+                col = 0;
+                row += 1;
+                if row == self.rows {
+                    row = 0
+                }
+            }
+        }
+    }
+
+    /// Scroll the whole screen up by `amount` lines (down, if negative), the
+    /// lines going off the top into the scrollback
+    pub fn scroll_down(&mut self, amount: isize) {
+        let rows = self.rows as isize;
+        let amount = amount.clamp(-rows, rows);
+        for line in 0..amount.max(0) as usize {
+            let start = self.real_index(line, 0);
+            self.scrollback
+                .push(&self.buffer[start..start + self.columns]);
+        }
+        if self.view_offset > 0 {
+            // Keep what's in view where it is, as it moves back through the
+            // scrollback
+            self.view_offset =
+                (self.view_offset + amount.max(0) as usize).min(self.scrollback.len());
+            self.history_dirty = true;
+        }
+        self.rotate(amount);
+    }
+
+    /// Move the whole screen up by `amount` lines (down, if negative) by
+    /// rotating the ring buffer, blanking the lines opened up
+    fn rotate(&mut self, amount: isize) {
+        let rows = self.rows as isize;
+        let amount = amount.clamp(-rows, rows);
+        self.top = (self.top as isize + amount).rem_euclid(rows) as usize;
+
+        match amount.cmp(&0) {
+            Ordering::Greater => {
+                for i in self.rows - amount as usize..self.rows {
+                    self.clear_line(i);
+                }
+            }
+            Ordering::Less => {
+                let amt = amount.unsigned_abs();
+                for i in 0..amt {
+                    self.clear_line(i);
+                }
+            }
+            _ => {}
+        }
+        if self.view_offset > 0 {
+            self.dirty_all();
+        } else {
+            // Everything but the lines just cleared is already drawn, only
+            // higher or lower up the screen
+            self.scrolled += amount;
+        }
+    }
+
+    /// Shift the characters from `col` to the end of `line` right by `n`,
+    /// blanking the cells opened up at `col`. Characters pushed past the right
+    /// edge are lost.
+    pub fn insert_chars(&mut self, line: usize, col: usize, n: usize) {
+        let n = n.min(self.columns - col);
+        self.cut_wide(line, col);
+        self.cut_wide(line, self.columns - n);
+        for c in (col + n..self.columns).rev() {
+            let ch = self.read_char(line, c - n);
+            self.write_char(line, c, ch);
+        }
+        let blank = self.colored(' ');
+        for c in col..col + n {
+            self.write_char(line, c, blank);
+        }
+    }
+
+    /// Remove `n` characters at `col`, shifting the rest of `line` left and
+    /// blanking the cells opened up at the end
+    pub fn delete_chars(&mut self, line: usize, col: usize, n: usize) {
+        let n = n.min(self.columns - col);
+        self.cut_wide(line, col);
+        self.cut_wide(line, col + n);
+        for c in col..self.columns - n {
+            let ch = self.read_char(line, c + n);
+            self.write_char(line, c, ch);
+        }
+        let blank = self.colored(' ');
+        for c in self.columns - n..self.columns {
+            self.write_char(line, c, blank);
+        }
+    }
+
+    /// Blank both halves of a double width character that's about to be cut
+    /// in two between `col - 1` and `col`, before the halves go separate ways
+    fn cut_wide(&mut self, line: usize, col: usize) {
+        if col < self.columns && self.read_char(line, col).is_wide_right() {
+            self.split_wide(line, col);
+            let blank = self.read_char(line, col).with_char(' ');
+            self.write_char(line, col, blank);
+        }
+    }
+
+    /// Blank `n` characters starting at `col`, without moving anything
+    pub fn erase_chars(&mut self, line: usize, col: usize, n: usize) {
+        for c in col..(col + n).min(self.columns) {
+            self.write(line, c, ' ');
+        }
+    }
+
+    /// Scroll the lines `top..=bottom` up by `amount` (down, if negative),
+    /// blanking the lines opened up and leaving the rest of the screen alone.
+    /// Lines scrolled off the top of the whole screen go into the scrollback.
+    pub fn scroll_region(&mut self, top: usize, bottom: usize, amount: isize) {
+        if top == 0 && bottom == self.rows - 1 {
+            self.scroll_down(amount);
+        } else {
+            self.shift_lines(top, bottom, amount);
+        }
+    }
+
+    /// Move the lines `top..=bottom` up by `amount` (down, if negative) like
+    /// `scroll_region`, except that nothing goes into the scrollback: the lines
+    /// IL and DL push out are gone for good
+    pub fn shift_lines(&mut self, top: usize, bottom: usize, amount: isize) {
+        if top == 0 && bottom == self.rows - 1 {
+            // The whole screen can get away with rotating the ring buffer
+            self.rotate(amount);
+            return;
+        }
+        let n = amount.unsigned_abs().min(bottom + 1 - top);
+        if amount > 0 {
+            for l in top..bottom + 1 - n {
+                self.copy_line(l + n, l);
+            }
+            for l in bottom + 1 - n..=bottom {
+                self.clear_line(l);
+            }
+        } else {
+            for l in (top + n..=bottom).rev() {
+                self.copy_line(l - n, l);
+            }
+            for l in top..top + n {
+                self.clear_line(l);
+            }
+        }
+    }
+
+    fn copy_line(&mut self, from: usize, to: usize) {
+        for c in 0..self.columns {
+            let ch = self.read_char(from, c);
+            self.write_char(to, c, ch);
+        }
+    }
+
+    pub fn clear_line(&mut self, line: usize) {
+        for c in 0..self.columns {
+            self.write(line, c, ' ');
+        }
+    }
+
+    /// Copy out the whole screen, top line first, to put back later with
+    /// `restore`. That takes a screen's worth of heap, so `None` if there isn't
+    /// that much to spare.
+    pub fn save(&self) -> Option<Vec<Character>> {
+        let mut cells = Vec::new();
+        cells.try_reserve_exact(self.buffer.len()).ok()?;
+        for line in 0..self.rows {
+            for col in 0..self.columns {
+                cells.push(self.read_char(line, col));
+            }
+        }
+        Some(cells)
+    }
+
+    /// Put back a screen copied out by `save`, if it's still the same shape
+    pub fn restore(&mut self, cells: &[Character]) {
+        if cells.len() != self.buffer.len() {
+            return;
+        }
+        self.top = 0;
+        self.buffer.copy_from_slice(cells);
+        self.dirty_all();
+    }
+
+    /// Page the view back through the scrollback by `lines` (forward, if
+    /// negative). The screen underneath keeps up with the host meanwhile.
+    pub fn scroll_view(&mut self, lines: isize) {
+        let offset = (self.view_offset as isize + lines).clamp(0, self.scrollback.len() as isize);
+        if offset as usize != self.view_offset {
+            self.view_offset = offset as usize;
+            self.history_dirty = self.view_offset > 0;
+            self.dirty_all();
+        }
+    }
+
+    /// Go back to showing the screen as it is
+    pub fn view_live(&mut self) {
+        self.scroll_view(-(self.view_offset as isize));
+    }
+
+    pub fn viewing_history(&self) -> bool {
+        self.view_offset > 0
+    }
+
+    /// Move blinking text on to its next phase, marking just the cells that
+    /// blink for redrawing
+    pub fn blink(&mut self) {
+        self.blink_visible = !self.blink_visible;
+        for i in 0..self.buffer.len() {
+            if self.buffer[i].color.blink() {
+                self.mark(i / self.columns, i % self.columns);
+            }
+        }
+    }
+
+    pub fn num_dirty(&self) -> usize {
+        self.num_dirty
+    }
+
+    pub fn dirty_all(&mut self) {
+        for line in self.dirty.iter_mut() {
+            line.insert_range(..);
+        }
+        self.dirty_lines.insert_range(..);
+        self.num_dirty = self.buffer.len();
+    }
+
+    #[inline(always)]
+    pub fn clear(&mut self) {
+        for row in 0..self.rows {
+            for col in 0..self.columns {
+                self.write(row, col, ' ');
+            }
+        }
+    }
+
+    /// Draw the character at `line`, `col` of the screen wherever it is in
+    /// view, which is nowhere if the view is paged far enough back
+    #[inline(always)]
+    pub fn draw<D>(&self, line: usize, col: usize, target: &mut D)
+    where
+        D: Frame,
+    {
+        let view_line = line + self.view_offset;
+        if view_line < self.rows {
+            let ch = self.read_char(line, col);
+            self.draw_cell(view_line, col, ch, self.cursor_at(line, col), target);
+        }
+    }
+
+    /// Draw the lines of scrollback above the screen, if the view is paged back
+    fn draw_history<D>(&mut self, target: &mut D)
+    where
+        D: Frame,
+    {
+        if !self.history_dirty {
+            return;
+        }
+        self.history_dirty = false;
+        for line in 0..self.view_offset.min(self.rows) {
+            for col in 0..self.columns {
+                let ch = self.scrollback.get(self.view_offset - line, col);
+                self.draw_character(line, col, ch, target);
+            }
+        }
+    }
+
+    pub fn draw_all<D>(&mut self, target: &mut D)
+    where
+        D: Frame,
+    {
+        self.repaint(target);
+        // Every cell is about to be drawn where it is now
+        self.scrolled = 0;
+        self.history_dirty = true;
+        self.draw_history(target);
+        for l in (0..self.rows).rev() {
+            for c in (0..self.columns).rev() {
+                self.draw(l, c, target);
+            }
+        }
+    }
+
+    #[inline(always)]
+    pub fn draw_dirty<D>(&mut self, target: &mut D)
+    where
+        D: Frame,
+    {
+        // Scrollback comes in whole pages at a time, and leaving it to here
+        // keeps `draw_dirty_up_to` short. It goes in after the target has
+        // caught up on scrolling, or it would be moved along with the rest.
+        if !self.scroll_target(target) {
+            return;
+        }
+        self.draw_history(target);
+        self.draw_dirty_up_to(usize::MAX, target);
+    }
+
+    /// Draw at most `up_to` of the cells marked for drawing, top line first
+    #[inline(always)]
+    pub fn draw_dirty_up_to<D>(&mut self, up_to: usize, target: &mut D)
+    where
+        D: Frame,
+    {
+        if self.num_dirty == 0 {
+            return;
+        }
+        self.repaint(target);
+        if !self.scroll_target(target) {
+            return;
+        }
+        let drawable = target.drawable_rows();
+        let mut budget = up_to;
+        for line in 0..self.rows {
+            if budget == 0 {
+                return;
+            }
+            let real_row = self.real_row(line);
+            if !self.dirty_lines.contains(real_row) {
+                continue;
+            }
+            if line + self.view_offset >= self.rows {
+                // Pushed off the bottom by the history in view. Going back
+                // to the live screen marks everything again anyway.
+                self.num_dirty -= self.dirty[real_row].count_ones(..);
+                self.dirty[real_row].clear();
+                self.dirty_lines.set(real_row, false);
+                continue;
+            }
+            // Lines the target isn't ready for wait for the next go
+            let rows = self.pixel_rows(line + self.view_offset);
+            if rows.start < drawable.start || rows.end > drawable.end {
+                continue;
+            }
+
+            let (mut drawn, mut end) = (0, 0);
+            for col in self.dirty[real_row].ones().take(budget) {
+                self.draw(line, col, target);
+                drawn += 1;
+                end = col + 1;
+            }
+            // Unmark what was drawn, which is everything up to where the
+            // budget ran out
+            self.dirty[real_row].set_range(..end, false);
+            if self.dirty[real_row].count_ones(..) == 0 {
+                self.dirty_lines.set(real_row, false);
+            }
+            self.num_dirty -= drawn;
+            budget -= drawn;
+        }
+    }
+
+    /// Blank the whole target after the grid changes shape, so nothing of
+    /// the old one is left showing around the edges of the new one
+    fn repaint<D>(&mut self, target: &mut D)
+    where
+        D: Frame,
+    {
+        if self.repaint {
+            self.repaint = false;
+            let _ = target.clear(Character::default().color.background());
+        }
+    }
+
+    /// The rows of pixels the grid's `line` covers
+    fn pixel_rows(&self, line: usize) -> Range<usize> {
+        let height = self.font.cell.height as usize;
+        let top = self.font.origin.y as usize + line * height;
+        top..top + height
+    }
+
+    /// Move what's drawn on the target by the lines scrolled since, and blank
+    /// the margins above and below the grid that the move leaves behind. A
+    /// target that can't move its pixels gets everything drawn again.
+    ///
+    /// Moving everything tears unless the whole target is ready for it, so
+    /// until it is this leaves the scroll for later and returns `false`, and
+    /// nothing else should be drawn meanwhile.
+    fn scroll_target<D>(&mut self, target: &mut D) -> bool
+    where
+        D: Frame,
+    {
+        if self.scrolled == 0 {
+            return true;
+        }
+        if target.drawable_rows() != (0..framebuffer::HEIGHT) {
+            return false;
+        }
+        let lines = core::mem::take(&mut self.scrolled);
+        let font = self.font;
+        let cell_height = font.cell.height as isize;
+        if lines.unsigned_abs() >= self.rows || !target.scroll(lines * cell_height) {
+            self.dirty_all();
+            return true;
+        }
+        let background = Character::default().color.background();
+        let bottom = font.origin.y + (self.rows as u32 * font.cell.height) as i32;
+        let width = framebuffer::WIDTH as u32;
+        let _ = target.fill_solid(
+            &Rectangle::new(Point::zero(), Size::new(width, font.origin.y as u32)),
+            background,
+        );
+        let _ = target.fill_solid(
+            &Rectangle::new(
+                Point::new(0, bottom),
+                Size::new(width, (framebuffer::HEIGHT as i32 - bottom).max(0) as u32),
+            ),
+            background,
+        );
+        true
+    }
+
+    #[inline(always)]
+    pub fn draw_character<D>(&self, line: usize, col: usize, character: Character, target: &mut D)
+    where
+        D: Frame,
+    {
+        self.draw_cell(line, col, character, None, target);
+    }
+
+    /// Draw `character` at `line`, `col` of the grid, with the cursor over it
+    /// if it's given a shape
+    #[inline(always)]
+    fn draw_cell<D>(
+        &self,
+        line: usize,
+        col: usize,
+        mut character: Character,
+        cursor: Option<CursorShape>,
+        target: &mut D,
+    ) where
+        D: Frame,
+    {
+        let font = self.font;
+        let hidden = character.color.blink() && !self.blink_visible;
+        if cursor == Some(CursorShape::Block) {
+            character.color.invert_colors();
+        }
+
+        if self.renderer == Renderer::Glyphs {
+            let top = target.top();
+            if let (Some(glyphs), Some(frame)) = (&self.glyphs, target.bytes()) {
+                let (fore, back) = character.colors();
+                // The right half of a double width character is drawn with
+                // the left
+                let glyph = (!hidden && !character.is_wide_right())
+                    .then(|| font.glyphs.glyph_mapping.index(character.char()));
+                let cell = blit::Cell {
+                    glyph,
+                    fore,
+                    back,
+                    underline: !hidden && character.color.underline(),
+                    strikethrough: !hidden && character.color.strikethrough(),
+                    overstruck: character.overstruck(),
+                    italic: character.color.italic(),
+                    underline_cursor: cursor == Some(CursorShape::Underline),
+                    bar_cursor: cursor == Some(CursorShape::Bar),
+                };
+                blit::draw(frame, top, font, glyphs, line, col, &cell);
+                return;
+            }
+        }
+
+        let (mut text, mut style) = character.text_and_style(font.glyphs);
+        if hidden {
+            // Decorations and all, leaving only the background
+            text = String::from(" ");
+            style.underline_color = DecorationColor::None;
+            style.strikethrough_color = DecorationColor::None;
+        }
+
+        let cell = font.origin
+            + Point::new(
+                (col as u32 * font.cell.width) as i32,
+                (line as u32 * font.cell.height) as i32,
+            );
+        let glyph_top = cell + font.glyph_offset();
+        let position = glyph_top + Point::new(0, font.glyphs.baseline as i32);
+
+        if font.cell != font.glyphs.character_size {
+            // Glyphs only paint the background behind themselves, so fill in
+            // the rest of the cell
+            if let Some(back) = style.background_color {
+                let _ = Rectangle::new(cell, font.cell)
+                    .into_styled(PrimitiveStyle::with_fill(back))
+                    .draw(target);
+            }
+        }
+
+        // The glyph on its own, without the background or decorations
+        let mut glyph = style;
+        glyph.background_color = None;
+        glyph.underline_color = DecorationColor::None;
+        glyph.strikethrough_color = DecorationColor::None;
+
+        // print!("d");
+        if character.color.italic() {
+            // Lay down the background and decorations first, so only the glyph
+            // gets slanted
+            let _ = Text::new(" ", position, style).draw(target);
+            let cells = if character.is_wide() { 2 } else { 1 };
+            let mut target = Italic {
+                target,
+                top: glyph_top.y,
+                height: font.glyphs.character_size.height as i32,
+                right: cell.x + (cells * font.cell.width) as i32,
+            };
+            let _ = Text::new(&text, position, glyph).draw(&mut target);
+            if character.overstruck() {
+                let _ = Text::new(&text, position + Point::new(1, 0), glyph).draw(&mut target);
+            }
+        } else {
+            let _ = Text::new(&text, position, style).draw(target);
+            if character.overstruck() {
+                // Draw the glyph again a pixel over
+                let _ = Text::new(&text, position + Point::new(1, 0), glyph).draw(target);
+            }
+        }
+
+        let thickness = blit::CURSOR_THICKNESS;
+        let cursor_line = match cursor {
+            Some(CursorShape::Underline) => Rectangle::new(
+                cell + Point::new(0, (font.cell.height - thickness) as i32),
+                Size::new(font.cell.width, thickness),
+            ),
+            Some(CursorShape::Bar) => Rectangle::new(cell, Size::new(thickness, font.cell.height)),
+            _ => return,
+        };
+        let _ = cursor_line
+            .into_styled(PrimitiveStyle::with_fill(character.colors().0))
+            .draw(target);
+    }
+}
+
+/// `font`'s glyphs for [`blit`], which draws a byte wide row of a cell at a
+/// time
+fn glyph_table(font: &Font) -> Option<Glyphs> {
+    if font.cell.width > blit::MAX_WIDTH {
+        return None;
+    }
+    Glyphs::new(font.glyphs)
+}
+
+impl Default for TextDisplay {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Slants what's drawn through it to the right, by shearing the top half of a
+/// text cell over by a pixel. A 6 pixel wide cell has no room for more, and
+/// what's pushed past `right` is dropped rather than drawn into the next cell,
+/// as [`blit`] does.
+struct Italic<'a, D> {
+    target: &'a mut D,
+    top: i32,
+    height: i32,
+    right: i32,
+}
+
+impl<D: DrawTarget> Dimensions for Italic<'_, D> {
+    fn bounding_box(&self) -> Rectangle {
+        self.target.bounding_box()
+    }
+}
+
+impl<D: DrawTarget> DrawTarget for Italic<'_, D> {
+    type Color = D::Color;
+    type Error = D::Error;
+
+    fn draw_iter<I>(&mut self, pixels: I) -> Result<(), Self::Error>
+    where
+        I: IntoIterator<Item = Pixel<Self::Color>>,
+    {
+        let (top, height, right) = (self.top, self.height, self.right);
+        self.target.draw_iter(
+            pixels
+                .into_iter()
+                .map(|Pixel(p, color)| {
+                    let shear = i32::from(p.y - top < height / 2);
+                    Pixel(p + Point::new(shear, 0), color)
+                })
+                .filter(|Pixel(p, _)| p.x < right),
+        )
+    }
+}
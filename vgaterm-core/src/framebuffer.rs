@@ -0,0 +1,146 @@
+//! An in-memory stand-in for `video::BUFFER`, so that the terminal can be
+//! rendered and inspected without any video hardware attached.
+
+use alloc::{vec, vec::Vec};
+use core::{convert::Infallible, ops::Range};
+
+use embedded_graphics::{prelude::*, Pixel};
+
+use crate::color::{self, Rgb3};
+
+pub const WIDTH: usize = 640;
+pub const HEIGHT: usize = 400;
+pub const BUFFER_SIZE: usize = WIDTH * HEIGHT;
+
+/// A target the terminal can draw into, and maybe write text cells straight
+/// into the pixels of. See [`crate::blit`].
+pub trait Frame: DrawTarget<Color = Rgb3> {
+    /// The target's pixels as color bytes, `WIDTH` to a row, if it keeps
+    /// them in memory like that
+    fn bytes(&mut self) -> Option<&mut [u8]> {
+        None
+    }
+
+    /// The row of `bytes` at the top of the picture. The rows after it
+    /// follow on down the screen, wrapping around from the last to the first.
+    fn top(&self) -> usize {
+        0
+    }
+
+    /// Move the picture up by `rows` pixel rows (down, if negative) by moving
+    /// `top`, so what goes off one edge comes back on at the other. `false`
+    /// if the target can't, and everything has to be drawn again instead.
+    fn scroll(&mut self, rows: isize) -> bool {
+        let _ = rows;
+        false
+    }
+
+    /// The rows of the picture that can be drawn on right now and still go
+    /// out whole in the next frame sent, rather than half in one and half in
+    /// the next. All of them, for a target that isn't being sent as it's
+    /// drawn.
+    fn drawable_rows(&self) -> Range<usize> {
+        0..HEIGHT
+    }
+}
+
+/// A 640x400 frame of hardware color bytes (see [`Rgb3::to_byte`]), laid out
+/// row by row exactly like the video buffer that gets shipped out over SPI,
+/// and scrolled the same way, by moving the row the picture starts on.
+pub struct FrameBuffer {
+    pixels: Vec<u8>,
+    top: usize,
+    drawable: Range<usize>,
+}
+
+impl FrameBuffer {
+    pub fn new() -> FrameBuffer {
+        FrameBuffer {
+            pixels: vec![0; BUFFER_SIZE],
+            top: 0,
+            drawable: 0..HEIGHT,
+        }
+    }
+
+    /// Have [`Frame::drawable_rows`] say only `rows` can be drawn on, like
+    /// the video buffer part way through sending a frame
+    pub fn set_drawable_rows(&mut self, rows: Range<usize>) {
+        self.drawable = rows;
+    }
+
+    /// The pixels in the order they're kept, which starts with the row at
+    /// [`Frame::top`] rather than the top of the picture
+    pub fn pixels(&self) -> &[u8] {
+        &self.pixels
+    }
+
+    /// The color byte shown at (x, y) of the picture
+    #[inline(always)]
+    pub fn pixel(&self, x: usize, y: usize) -> u8 {
+        self.pixels[index(self.top, x, y)]
+    }
+
+    /// The 8 bit per channel color the monitor would show at (x, y)
+    pub fn rgb(&self, x: usize, y: usize) -> (u8, u8, u8) {
+        color::rgb_from_byte(self.pixel(x, y))
+    }
+
+    pub fn clear(&mut self, color: Rgb3) {
+        self.pixels.fill(color.to_byte());
+    }
+}
+
+impl Default for FrameBuffer {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Frame for FrameBuffer {
+    fn bytes(&mut self) -> Option<&mut [u8]> {
+        Some(&mut self.pixels)
+    }
+
+    fn top(&self) -> usize {
+        self.top
+    }
+
+    fn scroll(&mut self, rows: isize) -> bool {
+        self.top = (self.top as isize + rows).rem_euclid(HEIGHT as isize) as usize;
+        true
+    }
+
+    fn drawable_rows(&self) -> Range<usize> {
+        self.drawable.clone()
+    }
+}
+
+/// Where pixel (x, y) of the picture is kept, in a frame starting at row `top`
+#[inline(always)]
+pub fn index(top: usize, x: usize, y: usize) -> usize {
+    ((y + top) % HEIGHT) * WIDTH + x
+}
+
+impl OriginDimensions for FrameBuffer {
+    fn size(&self) -> Size {
+        Size::new(WIDTH as u32, HEIGHT as u32)
+    }
+}
+
+impl DrawTarget for FrameBuffer {
+    type Color = Rgb3;
+    type Error = Infallible;
+
+    fn draw_iter<I>(&mut self, pixels: I) -> Result<(), Self::Error>
+    where
+        I: IntoIterator<Item = Pixel<Self::Color>>,
+    {
+        for Pixel(coord, color) in pixels.into_iter() {
+            if coord.x >= 0 && coord.x < WIDTH as i32 && coord.y >= 0 && coord.y < HEIGHT as i32 {
+                let i = index(self.top, coord.x as usize, coord.y as usize);
+                self.pixels[i] = color.to_byte();
+            }
+        }
+        Ok(())
+    }
+}
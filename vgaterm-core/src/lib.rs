@@ -0,0 +1,24 @@
+//! The hardware-independent half of vgaterm: escape sequence parsing, the
+//! character grid, and rendering that grid into anything that implements
+//! [`framebuffer::Frame`], which is a `DrawTarget<Color = Rgb3>` that can
+//! also hand over its pixels for text to be written straight into.
+//!
+//! Nothing in here touches a peripheral. The clock and log output are supplied
+//! by whoever is running the terminal through [`platform::configure`], so the
+//! same `TextField` can drive the esp32c3's video buffer or a
+//! [`framebuffer::FrameBuffer`] on a workstation.
+#![no_std]
+
+extern crate alloc;
+
+pub mod ansi;
+pub mod blit;
+pub mod color;
+pub mod display;
+pub mod framebuffer;
+pub mod platform;
+pub mod scrollback;
+pub mod terminal;
+pub mod text;
+pub mod utf8;
+pub mod width;
@@ -0,0 +1,49 @@
+//! Hooks the terminal core uses to reach the machine it's running on.
+//!
+//! The firmware points these at the esp32c3 `SYSTIMER` and the JTAG serial
+//! console. Left unconfigured, the clock stands still and log lines are
+//! dropped, which is what a test on a workstation usually wants anyway.
+
+use core::fmt;
+
+/// Ticks of a free running clock. Blink timings in the core assume the 16 MHz
+/// rate of the esp32c3 `SYSTIMER`.
+pub type Clock = fn() -> u64;
+
+/// Where the core's diagnostic `println!` output ends up
+pub type Logger = fn(fmt::Arguments);
+
+static mut CLOCK: Clock = stopped_clock;
+static mut LOGGER: Logger = discard_log;
+
+fn stopped_clock() -> u64 {
+    0
+}
+
+fn discard_log(_: fmt::Arguments) {}
+
+/// Install the clock and the logger. This should happen once at startup,
+/// before any `TextField` is created.
+pub fn configure(clock: Clock, logger: Logger) {
+    unsafe {
+        CLOCK = clock;
+        LOGGER = logger;
+    }
+}
+
+#[inline(always)]
+pub fn now() -> u64 {
+    unsafe { CLOCK() }
+}
+
+pub fn log(args: fmt::Arguments) {
+    unsafe { LOGGER(args) }
+}
+
+/// Like `esp_println::println`, but routed through the configured [`Logger`]
+#[macro_export]
+macro_rules! println {
+    ($($arg:tt)*) => {
+        $crate::platform::log(format_args!($($arg)*))
+    };
+}
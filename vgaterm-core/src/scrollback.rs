@@ -0,0 +1,74 @@
+//! Lines that have scrolled off the top of the screen, kept around so they can
+//! be paged back through
+
+use alloc::{collections::VecDeque, vec::Vec};
+
+use crate::display::Character;
+
+/// How many cells of history to keep by default. Each cell is 6 bytes, and the
+/// blank cells at the end of a line aren't kept, so this is usually good for a
+/// few screens of log output without eating the heap.
+pub const DEFAULT_CELLS: usize = 2048;
+
+/// What keeping a line costs on top of its cells: the `Vec` it's held in is
+/// 12 bytes on the esp32c3, as much as two cells. Without it a blank line
+/// would be free, and never pushed out by the budget.
+const LINE_CELLS: usize = 2;
+
+pub struct Scrollback {
+    lines: VecDeque<Vec<Character>>,
+    cells: usize,
+    max_cells: usize,
+}
+
+impl Scrollback {
+    /// A ring holding at most `max_cells` cells of history, counting
+    /// `LINE_CELLS` for each line. Zero keeps none.
+    pub fn new(max_cells: usize) -> Scrollback {
+        Scrollback {
+            lines: VecDeque::new(),
+            cells: 0,
+            max_cells,
+        }
+    }
+
+    /// How many lines there are to page back through
+    pub fn len(&self) -> usize {
+        self.lines.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.lines.is_empty()
+    }
+
+    /// Keep a line that has scrolled off the screen, dropping the oldest lines
+    /// if that goes over budget
+    pub fn push(&mut self, line: &[Character]) {
+        if self.max_cells == 0 {
+            return;
+        }
+        let len = line.len() - line.iter().rev().take_while(|c| c.is_blank()).count();
+        if len + LINE_CELLS > self.max_cells {
+            return;
+        }
+        self.cells += len + LINE_CELLS;
+        self.lines.push_back(line[..len].to_vec());
+        while self.cells > self.max_cells {
+            match self.lines.pop_front() {
+                Some(oldest) => self.cells -= oldest.len() + LINE_CELLS,
+                None => break,
+            }
+        }
+    }
+
+    /// The cell at `col` of the line `back` lines before the top of the
+    /// screen, where 1 is the line that scrolled off most recently
+    pub fn get(&self, back: usize, col: usize) -> Character {
+        self.len()
+            .checked_sub(back)
+            .and_then(|i| self.lines.get(i))
+            .and_then(|line| line.get(col))
+            .copied()
+            .unwrap_or_default()
+    }
+}
@@ -0,0 +1,751 @@
+use crate::{
+    ansi::{self, Charset, EraseMode, Op, OpChar, SetUnset, Style},
+    color::{self, Rgb3},
+    display::{Character, ColorDecs, CursorShape, Decoration, TextDisplay},
+    framebuffer::Frame,
+    platform, println,
+    scrollback::Scrollback,
+    text::{self, Font},
+    utf8::Utf8Decoder,
+    width,
+};
+use alloc::{
+    string::{String, ToString},
+    vec::Vec,
+};
+use fixedbitset::FixedBitSet;
+
+pub type Row = usize;
+pub type Col = usize;
+
+#[derive(Debug, Default, PartialEq, Eq, Clone, Copy)]
+pub struct CursorPos(pub Row, pub Col);
+
+impl CursorPos {
+    /// a cursor offset by r and c, which may be negative, kept within a grid
+    /// of `rows` by `cols`: rows and columns both saturate at its edges, see
+    /// `tests/cursor.rs`
+    #[inline]
+    pub fn offset(&self, r: isize, c: isize, rows: usize, cols: usize) -> CursorPos {
+        let col = (self.col() as isize + c).clamp(0, cols as isize - 1);
+        let row = (self.row() as isize + r).clamp(0, rows as isize - 1);
+
+        CursorPos(row as usize, col as usize)
+    }
+
+    #[inline]
+    pub fn row(&self) -> Row {
+        self.0
+    }
+
+    #[inline]
+    pub fn col(&self) -> Col {
+        self.1
+    }
+}
+
+/// Where the cursor is and how it looks. It's never written into the text,
+/// only drawn over it, see [`TextDisplay::set_cursor`].
+#[derive(Debug, Clone, Copy)]
+pub struct Cursor {
+    pub pos: CursorPos,
+    time_to_next_blink: Option<u64>,
+    blink_length: u64,
+    visible: bool,
+    shape: CursorShape,
+    blinking: bool,
+    /// Whether it's in the on phase of its blink
+    shown: bool,
+}
+
+impl Cursor {
+    /// The cursor moved by (r, c), kept within `text`'s grid. One that's
+    /// moved shows straight away, and starts its blink over.
+    fn offset(&self, r: isize, c: isize, text: &TextDisplay) -> Cursor {
+        let pos = self.pos.offset(r, c, text.rows(), text.columns());
+        if pos != self.pos {
+            return Cursor {
+                pos,
+                ..self.reset_blink()
+            };
+        }
+        *self
+    }
+
+    /// Show the cursor, and start its blink over
+    fn reset_blink(&self) -> Cursor {
+        Cursor {
+            time_to_next_blink: Some(platform::now().wrapping_add(self.blink_length)),
+            shown: true,
+            ..*self
+        }
+    }
+
+    /// Move the blink on to its next phase, if it's time. A steady cursor
+    /// stays on.
+    fn update(&self) -> Cursor {
+        let now = platform::now();
+        if let Some(time_to_next_blink) = self.time_to_next_blink {
+            if now >= time_to_next_blink {
+                return Cursor {
+                    time_to_next_blink: Some(now.wrapping_add(self.blink_length)),
+                    shown: !self.shown || !self.blinking,
+                    ..*self
+                };
+            }
+        }
+        *self
+    }
+
+    /// Where and how to draw the cursor right now, if at all
+    fn overlay(&self) -> Option<(Row, Col, CursorShape)> {
+        (self.visible && self.shown).then_some((self.pos.row(), self.pos.col(), self.shape))
+    }
+}
+
+impl Default for Cursor {
+    fn default() -> Self {
+        Cursor {
+            pos: Default::default(),
+            time_to_next_blink: Some(platform::now()),
+            blink_length: 12_000_000,
+            visible: true,
+            shape: CursorShape::default(),
+            blinking: true,
+            // Off, until the first blink turns it on
+            shown: false,
+        }
+    }
+}
+
+/// Keeps time for blinking text, which goes on and off every `blink_length`
+/// ticks
+#[derive(Debug, Clone, Copy)]
+struct TextBlink {
+    time_to_next_blink: u64,
+    blink_length: u64,
+}
+
+impl TextBlink {
+    fn update(&self, text: &mut TextDisplay) -> TextBlink {
+        let now = platform::now();
+        if now >= self.time_to_next_blink {
+            text.blink();
+            return TextBlink {
+                time_to_next_blink: now.wrapping_add(self.blink_length),
+                blink_length: self.blink_length,
+            };
+        }
+        *self
+    }
+}
+
+impl Default for TextBlink {
+    fn default() -> Self {
+        let blink_length = 8_000_000;
+        TextBlink {
+            time_to_next_blink: platform::now().wrapping_add(blink_length),
+            blink_length,
+        }
+    }
+}
+
+/// The primary screen, put aside while a program has the alternate one
+struct SavedScreen {
+    cells: Vec<Character>,
+    /// Only kept for mode 1049
+    cursor: Option<CursorPos>,
+    /// The colors and decorations text was being written in, kept along with
+    /// the cursor
+    color: Option<ColorDecs>,
+    /// The alternate screen doesn't add to the history
+    scrollback: Scrollback,
+}
+
+pub struct TextField {
+    pub text: TextDisplay,
+    cursor: Cursor,
+    saved_cursor: Option<CursorPos>,
+    text_blink: TextBlink,
+    parser: ansi::Parser,
+    /// Holds on to a character split between calls to `type_bytes`
+    decoder: Utf8Decoder,
+    redraw_started: Option<u64>,
+    /// The top and bottom lines of the scroll region, inclusive
+    scroll_top: Row,
+    scroll_bottom: Row,
+    primary_screen: Option<SavedScreen>,
+    /// The character sets designated as G0 and G1
+    charsets: [Charset; 2],
+    /// Whether SO has switched printing over to G1
+    shift_out: bool,
+    /// Whether printing past the last column carries on at the start of the
+    /// next line (DECAWM), rather than writing over the last column
+    autowrap: bool,
+    /// Set once a character has gone in the last column, with the cursor
+    /// left on it. The wrap only happens when the next character is printed,
+    /// so a line that exactly fills the width followed by `\r\n` doesn't leave
+    /// a blank line. Moving the cursor calls it off.
+    wrap_pending: bool,
+    /// The columns HT stops at
+    tab_stops: FixedBitSet,
+}
+
+impl TextField {
+    pub fn new() -> TextField {
+        TextField::with_font(&text::FONT_6X12)
+    }
+
+    /// A terminal with its grid laid out for `font`
+    pub fn with_font(font: &'static Font) -> TextField {
+        TextField {
+            text: TextDisplay::with_font(font),
+            cursor: Cursor::default(),
+            saved_cursor: None,
+            text_blink: TextBlink::default(),
+            parser: ansi::Parser::new(),
+            decoder: Utf8Decoder::new(),
+            redraw_started: None,
+            scroll_top: 0,
+            scroll_bottom: font.rows - 1,
+            primary_screen: None,
+            charsets: [Charset::Ascii; 2],
+            shift_out: false,
+            autowrap: true,
+            wrap_pending: false,
+            tab_stops: default_tab_stops(font.columns),
+        }
+    }
+
+    /// Switch over to `font`, starting again from a blank grid of its size
+    /// with the cursor at home
+    pub fn set_font(&mut self, font: &'static Font) {
+        // A primary screen put aside for the alternate one wouldn't fit the new
+        // grid, but its history still can
+        if let Some(primary) = self.primary_screen.take() {
+            self.text.scrollback = primary.scrollback;
+        }
+        self.text.set_font(font);
+        self.scroll_top = 0;
+        self.scroll_bottom = font.rows - 1;
+        self.tab_stops = default_tab_stops(font.columns);
+        self.saved_cursor = None;
+        self.cursor.pos = CursorPos::default();
+        self.cursor = self.cursor.reset_blink();
+        self.wrap_pending = false;
+    }
+
+    /// Moves the cursor by the given offset, and sets the cursor character to character
+    /// currently being selected by the new cursor position
+    pub fn move_cursor(&mut self, r: isize, c: isize) {
+        self.cursor = self.cursor.offset(r, c, &self.text);
+        self.wrap_pending = false;
+    }
+
+    /// Scroll the scroll region up by `amount` lines (down, if negative)
+    fn scroll(&mut self, amount: isize) {
+        self.text
+            .scroll_region(self.scroll_top, self.scroll_bottom, amount);
+    }
+
+    /// Move the cursor down a line, scrolling instead if it's on the bottom
+    /// line of the scroll region
+    fn index(&mut self) {
+        if self.cursor.pos.row() == self.scroll_bottom {
+            self.scroll(1);
+            self.wrap_pending = false;
+        } else {
+            self.move_cursor(1, 0);
+        }
+    }
+
+    /// Move the cursor up a line, scrolling instead if it's on the top line of
+    /// the scroll region
+    fn reverse_index(&mut self) {
+        if self.cursor.pos.row() == self.scroll_top {
+            self.scroll(-1);
+            self.wrap_pending = false;
+        } else {
+            self.move_cursor(-1, 0);
+        }
+    }
+
+    /// Switch to the alternate screen, keeping the primary one (and, if
+    /// asked, the cursor) to come back to. Rather than keep a second screen
+    /// around for good, the primary is only copied out to the heap while the
+    /// alternate screen is in use. So unless it's cleared, the alternate screen
+    /// starts out showing what the primary did.
+    fn enter_alternate_screen(&mut self, save_cursor: bool, clear: bool) {
+        if self.primary_screen.is_some() {
+            return;
+        }
+        self.text.view_live();
+        let Some(cells) = self.text.save() else {
+            println!("No room for the alternate screen");
+            return;
+        };
+        self.primary_screen = Some(SavedScreen {
+            cells,
+            cursor: save_cursor.then_some(self.cursor.pos),
+            color: save_cursor.then(|| self.text.current_color.clone()),
+            scrollback: core::mem::replace(&mut self.text.scrollback, Scrollback::new(0)),
+        });
+        if clear {
+            self.text.clear();
+        }
+    }
+
+    fn leave_alternate_screen(&mut self) {
+        let Some(primary) = self.primary_screen.take() else {
+            return;
+        };
+        self.text.view_live();
+        self.text.restore(&primary.cells);
+        self.text.scrollback = primary.scrollback;
+        if let Some(pos) = primary.cursor {
+            self.cursor.pos = pos;
+            self.wrap_pending = false;
+        }
+        if let Some(color) = primary.color {
+            self.text.current_color = color;
+        }
+    }
+
+    /// Page the view back through the scrollback by `lines` (forward, if
+    /// negative), without affecting the screen itself
+    pub fn scroll_view(&mut self, lines: isize) {
+        self.text.scroll_view(lines);
+    }
+
+    /// Return the view to the live screen, if it's been paged back
+    pub fn view_live(&mut self) {
+        self.text.view_live();
+    }
+
+    pub fn type_str(&mut self, s: &str) -> Vec<u8> {
+        // The parser is a field of ours, but handling what it emits needs all of
+        // `self`, so borrow it out for the duration
+        let mut parser = core::mem::take(&mut self.parser);
+        let mut outs = Vec::new();
+        for c in s.chars() {
+            parser.advance(c, |op| match op {
+                OpChar::Char(ch) => self.handle_char_in(ch),
+                OpChar::Op(op) => outs.extend(self.handle_op(op)),
+            });
+        }
+        self.parser = parser;
+        outs
+    }
+
+    /// Like `type_str`, but for bytes straight off the wire, which can end
+    /// partway through a character. The rest of it is picked up from the start
+    /// of the next call.
+    pub fn type_bytes(&mut self, bytes: &[u8]) -> Vec<u8> {
+        let mut s = String::with_capacity(bytes.len());
+        for &b in bytes {
+            self.decoder.decode(b, |c| s.push(c));
+        }
+        self.type_str(&s)
+    }
+
+    fn handle_char_in(&mut self, t: char) {
+        if t.is_ascii_control() {
+            // println!("ascii {}", t.escape_debug());
+        }
+        match t {
+            // Backspace only moves the cursor, what's there stays until it's
+            // written over
+            '\u{08}' => self.move_cursor(0, -1),
+            '\u{07}' => {
+                // Bell not impl
+            }
+            // VT and FF are taken as LF, as a VT does
+            '\n' | '\u{0B}' | '\u{0C}' => self.next_line(),
+            '\r' => self.move_cursor(0, -(self.cursor.pos.col() as isize)),
+            '\t' => self.tab(1),
+            // Shift out to G1, and back in to G0
+            '\u{0E}' => self.shift_out = true,
+            '\u{0F}' => self.shift_out = false,
+            // C1 IND, NEL, HTS and RI
+            '\u{84}' => self.index(),
+            '\u{85}' => self.next_line(),
+            '\u{88}' => self.tab_stops.insert(self.cursor.pos.col()),
+            '\u{8D}' => self.reverse_index(),
+            // NUL, DEL, and the rest of C0 and C1, do nothing
+            _ if t.is_control() => {}
+            _ => self.print(self.charsets[usize::from(self.shift_out)].map(t)),
+        }
+    }
+
+    /// Put a printable character down at the cursor and move past it. From
+    /// the last column, the move to the next line waits for the next
+    /// character, see `wrap_pending`.
+    fn print(&mut self, c: char) {
+        let wide = match width::char_width(c) {
+            // There's no drawing a combining mark over the last glyph, so it's
+            // dropped rather than taking a cell of its own
+            0 => return,
+            w => w == 2,
+        };
+        if self.wrap_pending {
+            self.next_line();
+        }
+        let last = self.text.columns() - 1;
+        if wide && self.cursor.pos.col() == last {
+            // Both halves have to be on the same line, so it goes on the next
+            // one, or over the last two columns of this one
+            if self.autowrap {
+                self.next_line();
+            } else {
+                self.move_cursor(0, -1);
+            }
+        }
+        let CursorPos(row, col) = self.cursor.pos;
+        if wide {
+            self.text.write_wide(row, col, c);
+        } else {
+            self.text.write(row, col, c);
+        }
+        let end = col + usize::from(wide);
+        if end < last {
+            self.move_cursor(0, end as isize + 1 - col as isize);
+        } else {
+            self.move_cursor(0, last as isize - col as isize);
+            self.wrap_pending = self.autowrap;
+        }
+    }
+
+    /// Move the cursor to the start of the next line, scrolling at the bottom
+    /// of the scroll region
+    fn next_line(&mut self) {
+        self.index();
+        self.move_cursor(0, -(self.cursor.pos.col() as isize));
+    }
+
+    /// Move the cursor on `n` tab stops (back, if negative). Past the last
+    /// stop it goes to the end of the line, and before the first to the start.
+    fn tab(&mut self, n: isize) {
+        let last = self.text.columns() - 1;
+        let mut col = self.cursor.pos.col();
+        for _ in 0..n.unsigned_abs() {
+            col = if n > 0 {
+                (col + 1..last)
+                    .find(|&c| self.tab_stops.contains(c))
+                    .unwrap_or(last)
+            } else {
+                (1..col)
+                    .rev()
+                    .find(|&c| self.tab_stops.contains(c))
+                    .unwrap_or(0)
+            };
+        }
+        self.move_cursor(0, col as isize - self.cursor.pos.col() as isize);
+    }
+
+    fn handle_op(&mut self, op: Op) -> Vec<u8> {
+        use Op::*;
+        // println!("{:?}", op);
+        let mut out = Vec::new();
+        match op {
+            MoveCursorAbs { x, y } => {
+                self.move_cursor(
+                    y as isize - self.cursor.pos.row() as isize,
+                    x as isize - self.cursor.pos.col() as isize,
+                );
+            }
+            MoveCursorAbsCol { x } => {
+                self.move_cursor(0, x as isize - self.cursor.pos.col() as isize);
+            }
+            MoveCursorDelta { dx, dy } => {
+                // Constrain dx and dy so that the result added to the current position
+                // stays within the window
+                let x = (self.cursor.pos.col() as isize + dx)
+                    .clamp(0, self.text.columns() as isize - 1)
+                    - self.cursor.pos.col() as isize;
+                // From inside the scroll region, the cursor stops at its margins
+                let row = self.cursor.pos.row();
+                let (top, bottom) = if (self.scroll_top..=self.scroll_bottom).contains(&row) {
+                    (self.scroll_top, self.scroll_bottom)
+                } else {
+                    (0, self.text.rows() - 1)
+                };
+                let y = (row as isize + dy).clamp(top as isize, bottom as isize) - row as isize;
+                self.move_cursor(y, x);
+            }
+            MoveCursorBeginningAndLine { dy } => {
+                self.move_cursor(dy, -(self.cursor.pos.col() as isize));
+            }
+            RequstCursorPos => {
+                // ESC [ <r> ; <c> R
+                let row = (self.cursor.pos.row() + 1).to_string();
+                let col = (self.cursor.pos.col() + 1).to_string();
+                out.extend_from_slice(&[27, b'[']);
+                out.extend_from_slice(row.as_str().as_bytes());
+                out.push(b';');
+                out.extend_from_slice(col.as_str().as_bytes());
+                out.push(b'R');
+            }
+            SetCursorStyle { shape, blinking } => {
+                self.cursor.shape = shape;
+                self.cursor.blinking = blinking;
+                self.cursor = self.cursor.reset_blink();
+            }
+            SaveCursorPos => {
+                self.saved_cursor.replace(self.cursor.pos);
+            }
+            RestoreCursorPos => {
+                if let Some(pos) = self.saved_cursor {
+                    self.move_cursor(
+                        pos.row() as isize - self.cursor.pos.row() as isize,
+                        pos.col() as isize - self.cursor.pos.col() as isize,
+                    );
+                }
+            }
+            EraseScreen(erase) => {
+                match erase {
+                    EraseMode::All => {
+                        self.text.clear();
+                    }
+                    EraseMode::FromCursor => {
+                        // Line the cursor is on
+                        for c in self.cursor.pos.col()..self.text.columns() {
+                            self.text.write(self.cursor.pos.row(), c, ' ');
+                        }
+                        // Rest of the screen
+                        for r in self.cursor.pos.row()..self.text.rows() {
+                            for c in 0..self.text.columns() {
+                                self.text.write(r, c, ' ');
+                            }
+                        }
+                    }
+                    EraseMode::ToCursor => {
+                        // All lines up to the cursor
+                        for r in 0..self.cursor.pos.row() {
+                            for c in 0..self.text.columns() {
+                                self.text.write(r, c, ' ');
+                            }
+                        }
+                        // Characters up to the cursor
+                        for c in 0..self.cursor.pos.col() {
+                            self.text.write(self.cursor.pos.row(), c, ' ');
+                        }
+                    }
+                }
+            }
+            EraseLine(erase) => {
+                let row = self.cursor.pos.row();
+                let columns = match erase {
+                    EraseMode::All => 0..self.text.columns(),
+                    EraseMode::FromCursor => self.cursor.pos.col()..self.text.columns(),
+                    EraseMode::ToCursor => 0..self.cursor.pos.col(),
+                };
+                for c in columns {
+                    self.text.write(row, c, ' ');
+                }
+            }
+            InsertLines { n } | DeleteLines { n } => {
+                // Lines only move around within the scroll region, so outside of
+                // it there's nothing to do
+                let row = self.cursor.pos.row();
+                if (self.scroll_top..=self.scroll_bottom).contains(&row) {
+                    let bottom = self.scroll_bottom;
+                    let amount = match op {
+                        InsertLines { .. } => -(n as isize),
+                        _ => n as isize,
+                    };
+                    self.text.shift_lines(row, bottom, amount);
+                    // Like a VT, the cursor goes back to the start of the line
+                    self.move_cursor(0, -(self.cursor.pos.col() as isize));
+                }
+            }
+            InsertChars { n } | DeleteChars { n } | EraseChars { n } => {
+                let CursorPos(row, col) = self.cursor.pos;
+                match op {
+                    InsertChars { .. } => self.text.insert_chars(row, col, n),
+                    DeleteChars { .. } => self.text.delete_chars(row, col, n),
+                    _ => self.text.erase_chars(row, col, n),
+                }
+            }
+            Scroll { delta } => self.scroll(delta),
+            SetScrollRegion { top, bottom } => {
+                let top = top.unwrap_or(0);
+                let last = self.text.rows() - 1;
+                let bottom = bottom.unwrap_or(last).min(last);
+                // A region needs at least two lines, anything else is ignored
+                if top < bottom {
+                    self.scroll_top = top;
+                    self.scroll_bottom = bottom;
+                    // and the cursor goes home
+                    self.move_cursor(
+                        -(self.cursor.pos.row() as isize),
+                        -(self.cursor.pos.col() as isize),
+                    );
+                }
+            }
+            DesignateCharset { g, charset } => self.charsets[g] = charset,
+            Index => self.index(),
+            ReverseIndex => self.reverse_index(),
+            SetTabStop => self.tab_stops.insert(self.cursor.pos.col()),
+            ClearTabStop => self.tab_stops.set(self.cursor.pos.col(), false),
+            ClearTabStops => self.tab_stops.clear(),
+            TabForward { n } => self.tab(n as isize),
+            TabBackward { n } => self.tab(-(n as isize)),
+            TextOp(op) => match op {
+                ansi::TextOp::SetFGBasic { fg } => {
+                    let (f, _) = color::ansi_base_color(fg, 0);
+                    self.text.current_color.fore = f;
+                }
+                ansi::TextOp::SetBGBasic { bg } => {
+                    let (_, b) = color::ansi_base_color(0, bg);
+                    self.text.current_color.back = b;
+                }
+                ansi::TextOp::SetTextMode(s, style) => {
+                    let current = &mut self.text.current_color;
+                    match (s, style) {
+                        (SetUnset::Set, Style::Inverse) => current.set(Decoration::Inverse),
+                        (SetUnset::Unset, Style::Inverse) => current.unset(Decoration::Inverse),
+                        (SetUnset::Set, Style::Strike) => current.set(Decoration::Strikethrough),
+                        (SetUnset::Unset, Style::Strike) => {
+                            current.unset(Decoration::Strikethrough)
+                        }
+                        (SetUnset::Set, Style::Blinking) => current.set(Decoration::Blink),
+                        (SetUnset::Unset, Style::Blinking) => current.unset(Decoration::Blink),
+                        (SetUnset::Set, Style::Underline) => current.set(Decoration::Underline),
+                        (SetUnset::Unset, Style::Underline) => current.unset(Decoration::Underline),
+                        (SetUnset::Set, Style::Italic) => current.set(Decoration::Italic),
+                        (SetUnset::Unset, Style::Italic) => current.unset(Decoration::Italic),
+                        (SetUnset::Set, Style::Bold) => current.set(Decoration::Bold),
+                        (SetUnset::Set, Style::Dim) => current.set(Decoration::Dim),
+                        (SetUnset::Unset, Style::Bold | Style::Dim) => {
+                            // `ESC [ 22 m` is back to normal intensity from
+                            // either
+                            current.unset(Decoration::Bold);
+                            current.unset(Decoration::Dim);
+                        }
+                    }
+                }
+                ansi::TextOp::SetFGColor256 { fg } => {
+                    let f = color::ansi_256_color(fg);
+                    println!("BG: {} => {:?}", fg, f.to_byte());
+                    self.text.current_color.fore = f;
+                }
+                ansi::TextOp::SetBGColor256 { bg } => {
+                    let b = color::ansi_256_color(bg);
+                    println!("BG: {} => {:?}", bg, b.to_byte());
+                    self.text.current_color.back = b;
+                }
+                ansi::TextOp::SetFGColorRgb { r, g, b } => {
+                    self.text.current_color.fore = Rgb3::from_rgb(r, g, b);
+                }
+                ansi::TextOp::SetBGColorRgb { r, g, b } => {
+                    self.text.current_color.back = Rgb3::from_rgb(r, g, b);
+                }
+                ansi::TextOp::ResetColors => {
+                    // Turn off attributes
+                    println!("Reset Colors");
+                    self.text.current_color.decs.clear();
+                    self.text.current_color.fore = color::ANSI_BASE_LOW_COLORS[7];
+                    self.text.current_color.back = color::ANSI_BASE_LOW_COLORS[0];
+                }
+            },
+            InPlaceDelete => self.text.write(self.cursor.pos.0, self.cursor.pos.1, ' '),
+            DecPrivateSet(op) => {
+                match op {
+                    25 => {
+                        println!("Cursor Visible");
+                        self.cursor.visible = true;
+                    },
+                    7 => self.autowrap = true,
+                    1049 => self.enter_alternate_screen(true, true),
+                    1047 => self.enter_alternate_screen(false, true),
+                    47 => self.enter_alternate_screen(false, false),
+                    _ => {}
+                }
+            }
+            DecPrivateReset(op) => {
+                match op {
+                    25 => {
+                        println!("Cursor Invisible");
+                        self.cursor.visible = false;
+                    },
+                    7 => {
+                        self.autowrap = false;
+                        self.wrap_pending = false;
+                    }
+                    1049 | 47 | 1047 => self.leave_alternate_screen(),
+                    _ => {}
+                }
+            }
+            Vgaterm(v) => {
+                match v {
+                    ansi::Vgaterm::Redraw => {
+                        self.text.dirty_all();
+                        self.redraw_started = Some(platform::now());
+                    },
+                    ansi::Vgaterm::SelectFont(n) => {
+                        if let Some(font) = text::FONTS.get(n) {
+                            self.set_font(font);
+                        }
+                    }
+                    ansi::Vgaterm::DebugDump => {
+                        
+                    }
+                }
+                
+            }
+        }
+        out
+    }
+
+    pub fn draw<D>(&mut self, target: &mut D)
+    where
+        D: Frame,
+    {
+        self.text_blink = self.text_blink.update(&mut self.text);
+        self.update_cursor();
+        self.text.draw_dirty(target);
+        self.report_redraw();
+    }
+
+    pub fn draw_up_to<D>(&mut self, up_to: usize, target: &mut D)
+    where
+        D: Frame,
+    {
+        self.text_blink = self.text_blink.update(&mut self.text);
+        self.update_cursor();
+        self.text.draw_dirty_up_to(up_to, target);
+        self.report_redraw();
+    }
+
+    /// Catch the cursor drawn over the text up with where it's got to since
+    /// the last draw, and the phase of its blink
+    fn update_cursor(&mut self) {
+        self.cursor = self.cursor.update();
+        self.text.set_cursor(self.cursor.overlay());
+    }
+
+    /// Once a requested full redraw (`ESC [ > V`) has made it to the target,
+    /// log how long it took
+    fn report_redraw(&mut self) {
+        if self.text.num_dirty() == 0 {
+            if let Some(started) = self.redraw_started.take() {
+                println!("Took {} ticks", platform::now().wrapping_sub(started));
+            }
+        }
+    }
+}
+
+/// A tab stop every 8 columns, as a VT starts out with
+fn default_tab_stops(columns: usize) -> FixedBitSet {
+    let mut stops = FixedBitSet::with_capacity(columns);
+    for col in (8..columns).step_by(8) {
+        stops.insert(col);
+    }
+    stops
+}
+
+impl Default for TextField {
+    fn default() -> Self {
+        Self::new()
+    }
+}
@@ -0,0 +1,333 @@
+use embedded_graphics::{
+    image::ImageRaw,
+    mono_font::{iso_8859_1, mapping::GlyphMapping, DecorationDimensions, MonoFont},
+    prelude::{Point, Size},
+};
+
+const RAW: &[u8; 3312] = &[
+    0x00, 0x00, 0x00, 0x10, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x85, 0x14, 0x11,
+    0x04, 0x08, 0x11, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x85, 0x14, 0x3a, 0x8a, 0x08, 0x20, 0x80,
+    0x00, 0x00, 0x00, 0x04, 0x00, 0x85, 0x3e, 0x42, 0xaa, 0x08, 0x20, 0x82, 0x08, 0x00, 0x00, 0x04,
+    0x00, 0x80, 0x14, 0x21, 0x44, 0x00, 0x40, 0x4a, 0x88, 0x00, 0x00, 0x08, 0x00, 0x80, 0x14, 0x10,
+    0x8a, 0x80, 0x40, 0x47, 0x3e, 0x03, 0xe0, 0x08, 0x00, 0x00, 0x3e, 0x09, 0x49, 0x00, 0x40, 0x4a,
+    0x88, 0x00, 0x00, 0x10, 0x00, 0x00, 0x14, 0x72, 0xa9, 0x00, 0x40, 0x42, 0x08, 0x60, 0x06, 0x10,
+    0x00, 0x80, 0x14, 0x20, 0xa6, 0x80, 0x20, 0x80, 0x00, 0x60, 0x06, 0x20, 0x00, 0x00, 0x00, 0x20,
+    0x40, 0x00, 0x20, 0x80, 0x00, 0x20, 0x00, 0x20, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x11, 0x00,
+    0x00, 0x40, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x1c, 0x70, 0x87, 0x3e, 0x13, 0xe3, 0x3e, 0x71, 0xc0,
+    0x00, 0x08, 0x08, 0x22, 0x89, 0x88, 0x82, 0x32, 0x04, 0x02, 0x8a, 0x26, 0x18, 0x10, 0x04, 0x02,
+    0x9a, 0x80, 0x84, 0x53, 0xc8, 0x04, 0x8a, 0x26, 0x18, 0x23, 0xe2, 0x04, 0xa8, 0x81, 0x0c, 0x90,
+    0x2f, 0x04, 0x71, 0xe0, 0x00, 0x40, 0x01, 0x08, 0xc8, 0x82, 0x02, 0xf8, 0x28, 0x88, 0x88, 0x20,
+    0x00, 0x23, 0xe2, 0x00, 0x88, 0x84, 0x22, 0x12, 0x28, 0x88, 0x88, 0x46, 0x18, 0x10, 0x04, 0x00,
+    0x73, 0xef, 0x9c, 0x11, 0xc7, 0x08, 0x71, 0x86, 0x18, 0x08, 0x08, 0x08, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x08, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x10, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x70, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x88, 0x8f, 0x0e, 0xf3, 0xef, 0x8e, 0x89, 0xc0,
+    0xa2, 0x82, 0x28, 0x9c, 0x89, 0x48, 0x90, 0x8a, 0x08, 0x10, 0x88, 0x80, 0xa4, 0x83, 0x6c, 0xa2,
+    0x9a, 0x28, 0xa0, 0x8a, 0x08, 0x20, 0x88, 0x80, 0xa8, 0x82, 0xaa, 0xa2, 0xaa, 0x2f, 0x20, 0x8b,
+    0xcf, 0x26, 0xf8, 0x80, 0xb0, 0x82, 0xa9, 0xa2, 0xbb, 0xe8, 0xa0, 0x8a, 0x08, 0x22, 0x88, 0x88,
+    0xa8, 0x82, 0x28, 0xa2, 0x82, 0x28, 0x90, 0x92, 0x08, 0x12, 0x88, 0x88, 0xa4, 0x82, 0x28, 0xa2,
+    0x82, 0x2f, 0x0e, 0xe3, 0xe8, 0x0e, 0x89, 0xc7, 0x22, 0xfa, 0x28, 0x9c, 0x78, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x1c, 0x01, 0xc0, 0x00, 0xf1, 0xcf, 0x1e, 0xfa, 0x28, 0xa2, 0x8a, 0x2f,
+    0x90, 0x80, 0x42, 0x00, 0x8a, 0x28, 0xa0, 0x22, 0x28, 0xa2, 0x8a, 0x21, 0x10, 0x80, 0x45, 0x00,
+    0x8a, 0x28, 0xa0, 0x22, 0x28, 0xa2, 0x51, 0x42, 0x10, 0x40, 0x48, 0x80, 0xf2, 0x2f, 0x1c, 0x22,
+    0x25, 0x2a, 0x20, 0x82, 0x10, 0x40, 0x40, 0x00, 0x82, 0x2a, 0x02, 0x22, 0x25, 0x2a, 0x50, 0x84,
+    0x10, 0x20, 0x40, 0x00, 0x82, 0x29, 0x02, 0x22, 0x22, 0x2a, 0x88, 0x84, 0x10, 0x20, 0x40, 0x00,
+    0x81, 0xc8, 0xbc, 0x21, 0xc2, 0x36, 0x88, 0x8f, 0x90, 0x10, 0x40, 0x00, 0x00, 0x40, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x10, 0x10, 0x40, 0x00, 0x00, 0x20, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x1c, 0x01, 0xc0, 0x3f, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x40, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x20, 0x08, 0x00, 0x08, 0x03, 0x80, 0x80, 0x81,
+    0x20, 0x60, 0x00, 0x00, 0x10, 0x08, 0x00, 0x08, 0x04, 0x00, 0x80, 0x00, 0x20, 0x20, 0x00, 0x00,
+    0x01, 0xcf, 0x1e, 0x79, 0xcf, 0x9e, 0xf1, 0x83, 0x24, 0x23, 0x6f, 0x1c, 0x00, 0x28, 0xa0, 0x8a,
+    0x24, 0x22, 0x88, 0x81, 0x28, 0x22, 0xa8, 0xa2, 0x01, 0xe8, 0xa0, 0x8b, 0xe4, 0x22, 0x88, 0x81,
+    0x38, 0x22, 0xa8, 0xa2, 0x02, 0x28, 0xa0, 0x8a, 0x04, 0x22, 0x88, 0x81, 0x24, 0x22, 0xa8, 0xa2,
+    0x01, 0xef, 0x1e, 0x79, 0xe4, 0x1e, 0x89, 0xc1, 0x22, 0x72, 0xa8, 0x9c, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x02, 0x00, 0x01, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x1c, 0x00, 0x06,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x06, 0x03, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x08, 0x20, 0x80, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x08, 0x20, 0x84, 0x80, 0x00, 0x00, 0x00, 0x40, 0x00, 0x00, 0x00, 0x00, 0x08, 0x20, 0x8a, 0x88,
+    0xf1, 0xeb, 0x9e, 0xfa, 0x28, 0xa2, 0x8a, 0x2f, 0x88, 0x20, 0x89, 0x00, 0x8a, 0x2c, 0x20, 0x42,
+    0x28, 0xa2, 0x52, 0x21, 0x30, 0x20, 0x60, 0x00, 0x8a, 0x28, 0x1c, 0x42, 0x28, 0xaa, 0x21, 0x42,
+    0x08, 0x20, 0x80, 0x08, 0x8a, 0x28, 0x02, 0x42, 0x25, 0x2a, 0x51, 0x44, 0x08, 0x20, 0x80, 0x08,
+    0xf1, 0xe8, 0x3c, 0x39, 0xe2, 0x36, 0x88, 0x8f, 0x88, 0x20, 0x80, 0x08, 0x80, 0x20, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x80, 0x08, 0x20, 0x80, 0x08, 0x80, 0x20, 0x00, 0x00, 0x00, 0x00, 0x03, 0x00,
+    0x06, 0x03, 0x00, 0x08, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x10, 0x10, 0x86, 0x94, 0x20, 0x00, 0x10, 0x20, 0x00, 0x00, 0x20,
+    0x00, 0x08, 0x21, 0x4b, 0x00, 0x50, 0x00, 0x08, 0x20, 0xc0, 0x22, 0x21, 0x80, 0x00, 0x00, 0x00,
+    0x08, 0x50, 0xe3, 0x80, 0x71, 0x08, 0x94, 0x22, 0x42, 0x08, 0x20, 0x82, 0x08, 0x20, 0xc4, 0x3e,
+    0x81, 0x07, 0x08, 0x22, 0x40, 0x08, 0x20, 0x82, 0x14, 0x21, 0x48, 0x20, 0x83, 0xc5, 0x3e, 0x01,
+    0x80, 0x14, 0x51, 0x45, 0x14, 0x51, 0x68, 0x3c, 0x81, 0x07, 0x08, 0x20, 0x02, 0x1c, 0x71, 0xc7,
+    0x1c, 0x71, 0xc8, 0x20, 0x71, 0x08, 0xbe, 0x20, 0x04, 0x22, 0x8a, 0x28, 0xa2, 0x8a, 0x44, 0x20,
+    0x23, 0xe0, 0x08, 0x20, 0x08, 0x22, 0x8a, 0x28, 0xa2, 0x8a, 0x63, 0xbe, 0x20, 0x00, 0x00, 0x20,
+    0x08, 0x80, 0x00, 0x00, 0x00, 0x00, 0x01, 0x00, 0x00, 0x00, 0x00, 0x00, 0x07, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x06, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x10, 0x85, 0x10, 0x10, 0x85, 0x00, 0x69, 0x01, 0x08, 0x69, 0x40, 0x00, 0x21, 0x40, 0x08, 0x21,
+    0x40, 0x00, 0xb0, 0x82, 0x14, 0xb0, 0x00, 0x02, 0x00, 0x0f, 0x80, 0x00, 0x07, 0x3c, 0x00, 0x00,
+    0x00, 0x01, 0xc0, 0x1c, 0xfb, 0xe8, 0x1c, 0x71, 0xc2, 0x12, 0x89, 0xc7, 0x1c, 0x72, 0x20, 0x26,
+    0x82, 0x08, 0x08, 0x20, 0x82, 0x12, 0xca, 0x28, 0xa2, 0x8a, 0x24, 0xa6, 0xf3, 0xcf, 0x08, 0x20,
+    0x82, 0x3a, 0xaa, 0x28, 0xa2, 0x8a, 0x23, 0x2a, 0x82, 0x08, 0x08, 0x20, 0x82, 0x12, 0x9a, 0x28,
+    0xa2, 0x8a, 0x23, 0x32, 0x82, 0x08, 0x08, 0x20, 0x82, 0x14, 0x8a, 0x28, 0xa2, 0x8a, 0x24, 0xb2,
+    0xfb, 0xef, 0x9c, 0x71, 0xc7, 0x38, 0x89, 0xc7, 0x1c, 0x71, 0xc0, 0x1c, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x20, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x40, 0x42, 0x14, 0x10, 0x00, 0x00, 0x00, 0x86, 0x80, 0x30, 0x00, 0x00, 0x20, 0x85, 0x00, 0x20,
+    0x00, 0x10, 0x11, 0x4b, 0x00, 0x48, 0x00, 0x10, 0x8a, 0x20, 0x22, 0x02, 0x07, 0x08, 0x20, 0x00,
+    0x14, 0x30, 0x00, 0x08, 0x8a, 0x28, 0xa2, 0x8b, 0xc8, 0x80, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x8a, 0x28, 0xa2, 0x8a, 0x29, 0x1c, 0x71, 0xc7, 0x1c, 0x73, 0x67, 0x1c, 0x8a, 0x28, 0xa2, 0x52,
+    0x2b, 0x02, 0x08, 0x20, 0x82, 0x08, 0xa8, 0x22, 0x8a, 0x28, 0xa2, 0x22, 0x28, 0x9e, 0x79, 0xe7,
+    0x9e, 0x79, 0xe8, 0x3e, 0x8a, 0x28, 0xa2, 0x23, 0xc8, 0xa2, 0x8a, 0x28, 0xa2, 0x8a, 0x88, 0x20,
+    0x71, 0xc7, 0x1c, 0x22, 0x0b, 0x1e, 0x79, 0xe7, 0x9e, 0x7b, 0x67, 0x1e, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x02, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x0c, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x80, 0x00, 0x00, 0x80, 0x08, 0x68, 0x00, 0x08, 0x68, 0x00, 0x00, 0x11, 0x40, 0x10, 0x11,
+    0x40, 0x0e, 0xb1, 0x01, 0x14, 0xb0, 0x00, 0x00, 0x20, 0x05, 0x08, 0x20, 0x05, 0x1c, 0x00, 0x82,
+    0x00, 0x01, 0x40, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x04, 0x00, 0x00, 0x00, 0x00, 0x02, 0x02,
+    0x71, 0xc7, 0x18, 0x61, 0x86, 0x1e, 0xf1, 0xc7, 0x1c, 0x71, 0xc0, 0x1c, 0x8a, 0x28, 0x88, 0x20,
+    0x82, 0x22, 0x8a, 0x28, 0xa2, 0x8a, 0x2f, 0xa6, 0xfb, 0xef, 0x88, 0x20, 0x82, 0x22, 0x8a, 0x28,
+    0xa2, 0x8a, 0x20, 0x2a, 0x82, 0x08, 0x08, 0x20, 0x82, 0x22, 0x8a, 0x28, 0xa2, 0x8a, 0x22, 0x32,
+    0x79, 0xe7, 0x9c, 0x71, 0xc7, 0x1c, 0x89, 0xc7, 0x1c, 0x71, 0xc0, 0x1c, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x20, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x02, 0x00, 0x00, 0x00, 0x20, 0x80, 0x10, 0x40, 0x80, 0x00, 0x00, 0x40, 0x45, 0x00, 0x10,
+    0x00, 0x30, 0x40, 0x30, 0x90, 0x80, 0xc3, 0x00, 0x20, 0x80, 0x14, 0x22, 0x05, 0x38, 0x20, 0x71,
+    0x12, 0x81, 0x24, 0x80, 0x00, 0x00, 0x00, 0x02, 0x00, 0x3c, 0x10, 0xf2, 0x12, 0xe1, 0x2b, 0x40,
+    0x8a, 0x28, 0xa2, 0x8b, 0xc8, 0xbe, 0x09, 0xf4, 0x12, 0x01, 0x2f, 0x40, 0x8a, 0x28, 0xa2, 0x8a,
+    0x28, 0xbf, 0x07, 0xf8, 0x14, 0x8b, 0xfe, 0xc0, 0x8a, 0x28, 0xa2, 0x8a, 0x28, 0xbf, 0x07, 0xf8,
+    0x18, 0xcb, 0xbd, 0xc0, 0x8a, 0x28, 0xa2, 0x8a, 0x28, 0xbe, 0x09, 0xf4, 0x10, 0xab, 0x1f, 0xc0,
+    0x79, 0xe7, 0x9e, 0x7b, 0xc7, 0xbc, 0x10, 0xf2, 0x10, 0x9b, 0xb5, 0x80, 0x00, 0x00, 0x00, 0x0a,
+    0x00, 0xb8, 0x20, 0x71, 0x10, 0x8b, 0xf3, 0x00, 0x00, 0x00, 0x00, 0x72, 0x07, 0x30, 0x40, 0x30,
+    0x80, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x20, 0x80, 0x10, 0x40, 0x00, 0x00, 0x00,
+    0x00, 0x02, 0x0c, 0x00, 0x02, 0x0c, 0x00, 0x02, 0x0c, 0x00, 0x00, 0x00, 0x00, 0x02, 0x0c, 0x00,
+    0x02, 0x0c, 0x00, 0x02, 0x0c, 0x00, 0x00, 0x00, 0x00, 0x02, 0x0c, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x02, 0x0c, 0x00, 0x00, 0x00, 0x00, 0x02, 0x0c, 0x00, 0x00, 0x00,
+    0x00, 0x02, 0x0c, 0x00, 0x02, 0x0c, 0x00, 0x02, 0x0c, 0x00, 0x00, 0x00, 0xff, 0xf2, 0x0c, 0xaa,
+    0xa2, 0x0c, 0xb6, 0xd0, 0x00, 0x3c, 0xf3, 0xcf, 0x03, 0xf2, 0x0c, 0x02, 0xa0, 0x00, 0x02, 0xd2,
+    0x0c, 0x20, 0xf3, 0x0f, 0x00, 0x02, 0x0c, 0x00, 0x00, 0x00, 0x00, 0x02, 0x0c, 0x20, 0x83, 0x0c,
+    0x00, 0x02, 0x0c, 0x00, 0x02, 0x0c, 0x00, 0x00, 0x00, 0x20, 0x83, 0x0c, 0x00, 0x02, 0x0c, 0x00,
+    0x02, 0x0c, 0x00, 0x02, 0x0c, 0x20, 0x83, 0x0c, 0x00, 0x02, 0x0c, 0x00, 0x00, 0x00, 0x00, 0x02,
+    0x0c, 0x20, 0x83, 0x0c, 0x00, 0x02, 0x0c, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x20, 0x83, 0x0c,
+    0x00, 0x00, 0x00, 0x20, 0x83, 0x0c, 0x20, 0x83, 0x0c, 0x20, 0x83, 0x08, 0x00, 0x00, 0x00, 0x20,
+    0x83, 0x0c, 0x20, 0x83, 0x0c, 0x20, 0x83, 0x08, 0x00, 0x00, 0x00, 0x20, 0x83, 0x0c, 0x20, 0x83,
+    0x0c, 0x20, 0x83, 0x08, 0x00, 0x00, 0x00, 0x20, 0x83, 0x0c, 0x20, 0x83, 0x0c, 0x20, 0x83, 0x08,
+    0x00, 0x00, 0x00, 0x20, 0x83, 0x0c, 0x20, 0x83, 0x0c, 0x20, 0x83, 0x08, 0xe3, 0x8f, 0x3c, 0x3c,
+    0xf3, 0xcf, 0xe3, 0x8f, 0x3c, 0x3c, 0xf3, 0xcf, 0x23, 0x83, 0x3c, 0x00, 0xf0, 0x0f, 0x03, 0x80,
+    0x38, 0x20, 0xf2, 0x0c, 0x20, 0x83, 0x0c, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x20, 0x82, 0x0c,
+    0x20, 0x83, 0x0c, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x20, 0x82, 0x0c, 0x20, 0x83, 0x0c, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x20, 0x82, 0x0c, 0x20, 0x83, 0x0c, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x20, 0x82, 0x0c, 0x20, 0x83, 0x0c, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x20, 0x82, 0x0c,
+    0x30, 0xc2, 0x0c, 0x20, 0x83, 0x08, 0x30, 0xc2, 0x0c, 0x00, 0x00, 0x00, 0x30, 0xc2, 0x0c, 0x20,
+    0x83, 0x08, 0x30, 0xc2, 0x0c, 0x00, 0x00, 0x00, 0x30, 0xc2, 0x0c, 0x20, 0x83, 0x08, 0x30, 0xc2,
+    0x0c, 0x00, 0x00, 0x00, 0x30, 0xc2, 0x0c, 0x20, 0x83, 0x08, 0x30, 0xc2, 0x0c, 0x00, 0x00, 0x00,
+    0x30, 0xc2, 0x0c, 0x20, 0x83, 0x08, 0x30, 0xc2, 0x0c, 0x00, 0x00, 0x00, 0x3c, 0xf3, 0xcf, 0xe3,
+    0x8f, 0x3c, 0xf3, 0xcf, 0x3c, 0xff, 0xff, 0xff, 0x30, 0xf3, 0xcf, 0x23, 0x82, 0x0c, 0x33, 0x8f,
+    0x3c, 0x23, 0x83, 0xff, 0x30, 0x83, 0x0c, 0x20, 0x82, 0x0c, 0x30, 0x83, 0x0c, 0x20, 0x82, 0x08,
+    0x30, 0x83, 0x0c, 0x20, 0x82, 0x0c, 0x30, 0x83, 0x0c, 0x20, 0x82, 0x08, 0x30, 0x83, 0x0c, 0x20,
+    0x82, 0x0c, 0x30, 0x83, 0x0c, 0x20, 0x82, 0x08, 0x30, 0x83, 0x0c, 0x20, 0x82, 0x0c, 0x30, 0x83,
+    0x0c, 0x20, 0x82, 0x08, 0x30, 0x83, 0x0c, 0x20, 0x82, 0x0c, 0x30, 0x83, 0x0c, 0x20, 0x82, 0x08,
+    0x00, 0x00, 0x00, 0x20, 0x82, 0x08, 0x30, 0xc3, 0x0c, 0x20, 0x82, 0x08, 0x00, 0x00, 0x00, 0x20,
+    0x82, 0x08, 0x30, 0xc3, 0x0c, 0x20, 0x82, 0x08, 0x00, 0x00, 0x00, 0x20, 0x82, 0x08, 0x30, 0xc3,
+    0x0c, 0x20, 0x82, 0x08, 0x00, 0x00, 0x00, 0x20, 0x82, 0x08, 0x30, 0xc3, 0x0c, 0x20, 0x82, 0x08,
+    0x00, 0x00, 0x00, 0x20, 0x82, 0x08, 0x30, 0xc3, 0x0c, 0x20, 0x82, 0x08, 0xff, 0xff, 0xff, 0xff,
+    0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0x33, 0xc3, 0xff, 0x03, 0x83, 0xff, 0x03, 0x83,
+    0xff, 0x23, 0x83, 0xff, 0x30, 0xc3, 0x0c, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x20, 0x82, 0x08,
+    0x30, 0xc3, 0x0c, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x20, 0x82, 0x08, 0x30, 0xc3, 0x0c, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x20, 0x82, 0x08, 0x30, 0xc3, 0x0c, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x20, 0x82, 0x08, 0x30, 0xc3, 0x0c, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x20, 0x82, 0x08,
+    0x30, 0x83, 0x0c, 0x30, 0x82, 0x0c, 0x20, 0xc3, 0x0c, 0x00, 0x02, 0x0c, 0x30, 0x83, 0x0c, 0x30,
+    0x82, 0x0c, 0x20, 0xc3, 0x0c, 0x00, 0x02, 0x0c, 0x30, 0x83, 0x0c, 0x30, 0x82, 0x0c, 0x20, 0xc3,
+    0x0c, 0x00, 0x02, 0x0c, 0x30, 0x83, 0x0c, 0x30, 0x82, 0x0c, 0x20, 0xc3, 0x0c, 0x00, 0x00, 0x00,
+    0x30, 0x83, 0x0c, 0x30, 0x82, 0x0c, 0x20, 0xc3, 0x0c, 0x00, 0x00, 0x00, 0xff, 0xff, 0xff, 0xff,
+    0xff, 0xff, 0xff, 0xff, 0xff, 0xdb, 0x60, 0x00, 0x20, 0xc3, 0x38, 0x3f, 0xc3, 0xff, 0xff, 0xc3,
+    0xff, 0x03, 0x62, 0x0c, 0x20, 0xc3, 0x08, 0x20, 0xc3, 0x08, 0x30, 0xc3, 0x0c, 0x00, 0x02, 0x0c,
+    0x20, 0xc3, 0x08, 0x20, 0xc3, 0x08, 0x30, 0xc3, 0x0c, 0x00, 0x02, 0x0c, 0x20, 0xc3, 0x08, 0x20,
+    0xc3, 0x08, 0x30, 0xc3, 0x0c, 0x00, 0x00, 0x00, 0x20, 0xc3, 0x08, 0x20, 0xc3, 0x08, 0x30, 0xc3,
+    0x0c, 0x00, 0x00, 0x00, 0x20, 0xc3, 0x08, 0x20, 0xc3, 0x08, 0x30, 0xc3, 0x0c, 0x00, 0x00, 0x00,
+    0x01, 0x40, 0x00, 0x00, 0x00, 0x00, 0x21, 0x45, 0x08, 0x51, 0x42, 0x14, 0x01, 0x40, 0x00, 0x00,
+    0x00, 0x00, 0x21, 0x45, 0x08, 0x51, 0x42, 0x14, 0x01, 0x40, 0x00, 0x00, 0x00, 0x00, 0x21, 0x45,
+    0x08, 0x51, 0x42, 0x14, 0x01, 0x40, 0x00, 0x00, 0x00, 0x00, 0x21, 0x45, 0x08, 0x51, 0x42, 0x14,
+    0xfd, 0x43, 0xc0, 0x7f, 0x80, 0x3c, 0x3d, 0x45, 0xf8, 0x53, 0x43, 0xd4, 0x01, 0x42, 0x1f, 0x40,
+    0x8f, 0x04, 0x21, 0xf4, 0x08, 0xf0, 0x42, 0x1f, 0xfd, 0x43, 0xd4, 0x5f, 0x85, 0x34, 0x3c, 0x07,
+    0xf8, 0x03, 0xc3, 0xd4, 0x01, 0x42, 0x14, 0x50, 0x85, 0x14, 0x00, 0x00, 0x00, 0x00, 0x02, 0x14,
+    0x01, 0x42, 0x14, 0x50, 0x85, 0x14, 0x00, 0x00, 0x00, 0x00, 0x02, 0x14, 0x01, 0x42, 0x14, 0x50,
+    0x85, 0x14, 0x00, 0x00, 0x00, 0x00, 0x02, 0x14, 0x01, 0x42, 0x14, 0x50, 0x85, 0x14, 0x00, 0x00,
+    0x00, 0x00, 0x02, 0x14, 0x01, 0x42, 0x14, 0x50, 0x85, 0x14, 0x00, 0x00, 0x00, 0x00, 0x02, 0x14,
+    0x50, 0x85, 0x14, 0x00, 0x00, 0x08, 0x51, 0x42, 0x14, 0x50, 0x00, 0x08, 0x50, 0x85, 0x14, 0x00,
+    0x00, 0x08, 0x51, 0x42, 0x14, 0x50, 0x00, 0x08, 0x50, 0x85, 0x14, 0x00, 0x00, 0x08, 0x51, 0x42,
+    0x14, 0x50, 0x00, 0x08, 0x50, 0x85, 0x14, 0x00, 0x00, 0x08, 0x51, 0x42, 0x14, 0x50, 0x00, 0x08,
+    0x5f, 0x85, 0x34, 0xfc, 0x0f, 0xff, 0x53, 0x7f, 0xd4, 0xdc, 0x00, 0x08, 0x40, 0x8f, 0x04, 0x23,
+    0xf0, 0x08, 0xfc, 0x02, 0x3f, 0x00, 0x7c, 0x30, 0x5f, 0x85, 0x34, 0xfd, 0x4d, 0xff, 0x03, 0xff,
+    0xd4, 0xdc, 0x82, 0x00, 0x50, 0x85, 0x14, 0x21, 0x45, 0x00, 0x00, 0x02, 0x14, 0x50, 0x82, 0x00,
+    0x50, 0x85, 0x14, 0x21, 0x45, 0x00, 0x00, 0x02, 0x14, 0x50, 0x82, 0x00, 0x50, 0x85, 0x14, 0x21,
+    0x45, 0x00, 0x00, 0x02, 0x14, 0x50, 0x82, 0x00, 0x50, 0x85, 0x14, 0x21, 0x45, 0x00, 0x00, 0x02,
+    0x14, 0x50, 0x82, 0x00, 0x50, 0x85, 0x14, 0x21, 0x45, 0x00, 0x00, 0x02, 0x14, 0x50, 0x82, 0x00,
+    0x20, 0x18, 0x21, 0x00, 0x80, 0x00, 0x00, 0xc0, 0x00, 0x00, 0x80, 0x0c, 0x20, 0x3c, 0x33, 0x00,
+    0x80, 0x00, 0x00, 0xc0, 0x00, 0x00, 0x80, 0x0c, 0x20, 0x24, 0x12, 0x00, 0x80, 0x00, 0x00, 0xc0,
+    0x00, 0x00, 0x80, 0x0c, 0x20, 0x66, 0x1e, 0x00, 0x80, 0x00, 0x00, 0xc0, 0x00, 0x00, 0x80, 0x0c,
+    0x20, 0x42, 0x0c, 0x00, 0x80, 0x00, 0x00, 0xc0, 0x00, 0x00, 0x80, 0x0c, 0x1c, 0x42, 0x0c, 0xe0,
+    0x83, 0xc8, 0xe0, 0xc3, 0xcc, 0xfc, 0xcf, 0xcc, 0x00, 0x83, 0x0c, 0x00, 0x00, 0x08, 0xe0, 0x03,
+    0xcc, 0x3c, 0xce, 0x08, 0x00, 0x81, 0x0c, 0x00, 0x00, 0x08, 0x00, 0x00, 0x0c, 0x00, 0xc0, 0x08,
+    0x01, 0x81, 0x9e, 0x00, 0x00, 0x08, 0x00, 0x00, 0x0c, 0x00, 0xc0, 0x08, 0x01, 0x00, 0x92, 0x00,
+    0x00, 0x08, 0x00, 0x00, 0x0c, 0x00, 0xc0, 0x08, 0x03, 0x00, 0xf3, 0x00, 0x00, 0x08, 0x00, 0x00,
+    0x0c, 0x00, 0xc0, 0x08, 0x02, 0x00, 0x61, 0x00, 0x00, 0x08, 0x00, 0x00, 0x0c, 0x00, 0xc0, 0x08,
+    0xfc, 0x00, 0x00, 0x00, 0x00, 0x00, 0xff, 0xef, 0x3c, 0xe3, 0x0c, 0x20, 0xfc, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0xff, 0xef, 0x3c, 0xe3, 0x0c, 0x20, 0xfc, 0x00, 0x00, 0x00, 0x00, 0x3f, 0xff, 0xef,
+    0x3c, 0xe3, 0x0c, 0x20, 0xfc, 0x00, 0x00, 0x00, 0x0f, 0xff, 0xff, 0xef, 0x3c, 0xe3, 0x0c, 0x20,
+    0xfc, 0x00, 0x00, 0x03, 0xff, 0xff, 0xff, 0xef, 0x3c, 0xe3, 0x0c, 0x20, 0xfc, 0x00, 0x00, 0x03,
+    0xff, 0xff, 0xff, 0xef, 0x3c, 0xe3, 0x0c, 0x20, 0x00, 0x00, 0x00, 0xff, 0xff, 0xff, 0xff, 0xef,
+    0x3c, 0xe3, 0x0c, 0x20, 0x00, 0x00, 0x00, 0xff, 0xff, 0xff, 0xff, 0xef, 0x3c, 0xe3, 0x0c, 0x20,
+    0x00, 0x00, 0x3f, 0xff, 0xff, 0xff, 0xff, 0xef, 0x3c, 0xe3, 0x0c, 0x20, 0x00, 0x0f, 0xff, 0xff,
+    0xff, 0xff, 0xff, 0xef, 0x3c, 0xe3, 0x0c, 0x20, 0x03, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xef,
+    0x3c, 0xe3, 0x0c, 0x20, 0x03, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xef, 0x3c, 0xe3, 0x0c, 0x20,
+    0x1e, 0xaa, 0xbf, 0xfc, 0x10, 0x00, 0xe3, 0x8e, 0x3f, 0xfc, 0x71, 0xc7, 0x1c, 0x05, 0x6a, 0xfc,
+    0x10, 0x00, 0xe3, 0x8e, 0x3f, 0xfc, 0x71, 0xc7, 0x1e, 0xaa, 0xbf, 0x00, 0x10, 0x00, 0xe3, 0x8e,
+    0x3f, 0xfc, 0x71, 0xc7, 0x1c, 0x05, 0x6a, 0x00, 0x10, 0x00, 0xe3, 0x8e, 0x3f, 0xfc, 0x71, 0xc7,
+    0x1e, 0xaa, 0xbf, 0x00, 0x10, 0x00, 0xe3, 0x8e, 0x3f, 0xfc, 0x71, 0xc7, 0x1c, 0x05, 0x6a, 0x00,
+    0x10, 0x00, 0xe3, 0x8e, 0x3f, 0xfc, 0x71, 0xc7, 0x1e, 0xaa, 0xbf, 0x00, 0x1e, 0x07, 0x03, 0xf1,
+    0xf8, 0x1c, 0x0e, 0x3f, 0x1c, 0x05, 0x6a, 0x00, 0x1e, 0x07, 0x03, 0xf1, 0xf8, 0x1c, 0x0e, 0x3f,
+    0x1e, 0xaa, 0xbf, 0x00, 0x1e, 0x07, 0x03, 0xf1, 0xf8, 0x1c, 0x0e, 0x3f, 0x1c, 0x05, 0x6a, 0x00,
+    0x1e, 0x07, 0x03, 0xf1, 0xf8, 0x1c, 0x0e, 0x3f, 0x1e, 0xaa, 0xbf, 0x00, 0x1e, 0x07, 0x03, 0xf1,
+    0xf8, 0x1c, 0x0e, 0x3f, 0x1c, 0x05, 0x6a, 0x00, 0x1e, 0x07, 0x03, 0xf1, 0xf8, 0x1c, 0x0e, 0x3f,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x02, 0x88, 0x28, 0xe1,
+    0x8a, 0x3f, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x02, 0x88, 0x28, 0x82, 0x0e, 0x00, 0x00, 0x00,
+    0x02, 0x80, 0x00, 0x00, 0x23, 0x88, 0x28, 0xc2, 0x0e, 0x00, 0xfc, 0x00, 0x04, 0x40, 0x00, 0x88,
+    0x72, 0x88, 0x28, 0x82, 0x0e, 0x00, 0x00, 0x00, 0x08, 0x23, 0xef, 0x88, 0xfa, 0x8e, 0x10, 0x81,
+    0x8a, 0x00, 0x00, 0x00, 0x10, 0x11, 0x41, 0x3e, 0x70, 0x71, 0xc7, 0x1c, 0x61, 0x00, 0x00, 0x00,
+    0x08, 0x21, 0x42, 0x08, 0x20, 0x21, 0x02, 0x10, 0x51, 0x00, 0x03, 0xf0, 0x04, 0x41, 0x4f, 0x88,
+    0x00, 0x21, 0x82, 0x18, 0x61, 0x00, 0x00, 0x00, 0x02, 0x81, 0x24, 0x00, 0x00, 0x21, 0x02, 0x10,
+    0x51, 0x00, 0x00, 0x0f, 0xc0, 0x00, 0x00, 0x3e, 0x00, 0x21, 0x02, 0x10, 0x51, 0xc0, 0x00, 0x00,
+    0x3e, 0xf8, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x60, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x60, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+];
+
+/// The glyph for U+FFFD, shown for anything the font doesn't cover
+const REPLACEMENT: usize = 174;
+
+struct Mapping;
+
+impl GlyphMapping for Mapping {
+    fn index(&self, c: char) -> usize {
+        match c {
+            ' '..='~' => c as usize - ' ' as usize,
+            '¡'..='¦' => (c as usize - '¡' as usize) + 95,
+            '°'..='°' => (c as usize - '°' as usize) + 101,
+            '¿'..='ÿ' => (c as usize - '¿' as usize) + 102,
+            // Powerline's separator arrows
+            '\u{E0B0}'..='\u{E0B3}' => (c as usize - '\u{E0B0}' as usize) + 167,
+            // Powerline's branch, line number and padlock
+            '\u{E0A0}'..='\u{E0A2}' => (c as usize - '\u{E0A0}' as usize) + 171,
+            // Box drawing, then block elements, U+2500 to U+259F
+            '─'..='▟' => (c as usize - '─' as usize) + 176,
+            // The rest of DEC Special Graphics
+            '◆' => 336,
+            '␉'..='␍' => (c as usize - '␉' as usize) + 337,
+            '␤' => 342,
+            '⎺'..='⎽' => (c as usize - '⎺' as usize) + 343,
+            '≤' => 347,
+            '≥' => 348,
+            'π' => 349,
+            '≠' => 350,
+            '±' => 351,
+            '·' => 352,
+            _ => REPLACEMENT,
+        }
+    }
+}
+
+const GLYPH_MAPPING: Mapping = Mapping;
+
+#[allow(non_upper_case_globals)]
+pub const TAMZEN_FONT_6x12: MonoFont = MonoFont {
+    image: ImageRaw::new_binary(RAW, 96),
+    character_size: Size::new(6, 12),
+    character_spacing: 0,
+    baseline: 11,
+    strikethrough: DecorationDimensions::default_strikethrough(12),
+    // On the bottom row, rather than the default of two below the glyph,
+    // which is out of the cell and into the line below
+    underline: DecorationDimensions::new(11, 1),
+    glyph_mapping: &GLYPH_MAPPING,
+};
+
+/// embedded-graphics' 5x8 glyphs, but with the underline on their bottom row.
+/// Its own is two below the baseline, in the line below, where it's wiped out
+/// whenever that line is drawn.
+const FONT_5X8_GLYPHS: MonoFont = MonoFont {
+    underline: DecorationDimensions::new(7, 1),
+    ..iso_8859_1::FONT_5X8
+};
+
+/// A bitmap font, and the grid of text cells it lays out on the screen
+pub struct Font {
+    /// Named for the size of its cells, which needn't be its glyphs'
+    pub name: &'static str,
+    pub glyphs: &'static MonoFont<'static>,
+    /// The size of a text cell. It can be bigger than the glyphs, which sit
+    /// in the middle of it.
+    pub cell: Size,
+    pub columns: usize,
+    pub rows: usize,
+    /// The screen position of the top left cell
+    pub origin: Point,
+}
+
+impl Font {
+    /// Where a glyph goes in its cell
+    pub fn glyph_offset(&self) -> Point {
+        let size = self.glyphs.character_size;
+        Point::new(
+            (self.cell.width.saturating_sub(size.width) / 2) as i32,
+            (self.cell.height.saturating_sub(size.height) / 2) as i32,
+        )
+    }
+}
+
+/// 105x33, and the only font with box drawing
+pub const FONT_6X12: Font = Font {
+    name: "6x12",
+    glyphs: &TAMZEN_FONT_6x12,
+    cell: Size::new(6, 12),
+    columns: 105,
+    rows: 33,
+    origin: Point::new(2, 1),
+};
+
+/// 80x25, like a PC text mode: embedded-graphics' 8x13 glyphs in the middle
+/// of 8x16 cells. They only cover ISO 8859-1, so box drawing and the DEC
+/// special graphics come out as the font's `?`.
+pub const FONT_8X16: Font = Font {
+    name: "8x16",
+    glyphs: &iso_8859_1::FONT_8X13,
+    cell: Size::new(8, 16),
+    columns: 80,
+    rows: 25,
+    origin: Point::new(0, 0),
+};
+
+/// 128x50, for fitting a lot on screen. Like [`FONT_8X16`], only ISO 8859-1,
+/// without box drawing or the DEC special graphics.
+pub const FONT_5X8: Font = Font {
+    name: "5x8",
+    glyphs: &FONT_5X8_GLYPHS,
+    cell: Size::new(5, 8),
+    columns: 128,
+    rows: 50,
+    origin: Point::new(0, 0),
+};
+
+/// The fonts to choose from, numbered as for `ESC [ > <n> F`
+pub const FONTS: [&Font; 3] = [&FONT_6X12, &FONT_8X16, &FONT_5X8];
@@ -0,0 +1,97 @@
+//! UTF-8 decoding a byte at a time, for input that arrives in whatever pieces
+//! the UART hands over
+//!
+//! Bad input turns into U+FFFD the way the WHATWG Encoding Standard (and
+//! `String::from_utf8_lossy`) does it: one replacement character for each
+//! maximal run of bytes that could have started a character, and a byte that
+//! breaks off a sequence starts afresh rather than being swallowed with it.
+
+/// Shown in place of input that isn't UTF-8
+pub const REPLACEMENT: char = '\u{FFFD}';
+
+#[derive(Debug, Clone, Copy)]
+pub struct Utf8Decoder {
+    /// The bits of the character so far
+    code_point: u32,
+    /// How many more continuation bytes the character needs
+    needed: u8,
+    /// The range the next continuation byte has to be in. It's narrower than
+    /// 0x80..=0xBF just after some lead bytes, to rule out overlong forms,
+    /// surrogates and code points past U+10FFFF.
+    lower: u8,
+    upper: u8,
+}
+
+impl Utf8Decoder {
+    pub const fn new() -> Utf8Decoder {
+        Utf8Decoder {
+            code_point: 0,
+            needed: 0,
+            lower: 0x80,
+            upper: 0xBF,
+        }
+    }
+
+    /// Whether the decoder is partway through a character
+    pub fn pending(&self) -> bool {
+        self.needed > 0
+    }
+
+    /// Take the next byte, passing `emit` each character it completes. That's
+    /// usually none or one, but a byte that cuts a sequence short gives a
+    /// replacement character for the sequence and then whatever it is itself.
+    pub fn decode(&mut self, byte: u8, mut emit: impl FnMut(char)) {
+        if self.needed > 0 {
+            if !(self.lower..=self.upper).contains(&byte) {
+                *self = Utf8Decoder::new();
+                emit(REPLACEMENT);
+                // and go on to take `byte` as the start of something new
+            } else {
+                self.lower = 0x80;
+                self.upper = 0xBF;
+                self.code_point = self.code_point << 6 | (byte & 0x3F) as u32;
+                self.needed -= 1;
+                if self.needed == 0 {
+                    emit(char::from_u32(self.code_point).unwrap_or(REPLACEMENT));
+                    self.code_point = 0;
+                }
+                return;
+            }
+        }
+
+        match byte {
+            0x00..=0x7F => emit(byte as char),
+            0xC2..=0xDF => {
+                self.needed = 1;
+                self.code_point = (byte & 0x1F) as u32;
+            }
+            0xE0..=0xEF => {
+                match byte {
+                    0xE0 => self.lower = 0xA0,
+                    0xED => self.upper = 0x9F,
+                    _ => {}
+                }
+                self.needed = 2;
+                self.code_point = (byte & 0x0F) as u32;
+            }
+            0xF0..=0xF4 => {
+                match byte {
+                    0xF0 => self.lower = 0x90,
+                    0xF4 => self.upper = 0x8F,
+                    _ => {}
+                }
+                self.needed = 3;
+                self.code_point = (byte & 0x07) as u32;
+            }
+            // Stray continuation bytes, and lead bytes that can only start
+            // overlong or out of range forms
+            _ => emit(REPLACEMENT),
+        }
+    }
+}
+
+impl Default for Utf8Decoder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
@@ -0,0 +1,33 @@
+//! Color bytes, the 8 bits per pixel the video hardware reads, taken back
+//! apart into RGB
+//!
+//! ```text
+//! cargo test-core --test color
+//! ```
+
+use vgaterm_core::color::{byte_to_rgb3, color3_to_byte, rgb_from_byte};
+
+#[test]
+fn byte_to_rgb() {
+    assert_eq!(rgb_from_byte(0), (0, 0, 0));
+    assert_eq!(rgb_from_byte(0xFF), (255, 255, 255));
+    // Red's two high bits, then green's three and blue's three
+    assert_eq!(rgb_from_byte(0b0000_0011), (218, 0, 0));
+    assert_eq!(rgb_from_byte(0b0001_1100), (0, 255, 0));
+    // The top bit is blue's highest and red's lowest at once
+    assert_eq!(rgb_from_byte(0b1000_0000), (36, 0, 145));
+    // but the one under it is blue's alone
+    assert_eq!(rgb_from_byte(0b0100_0000), (0, 0, 72));
+}
+
+#[test]
+fn every_byte() {
+    for byte in 0..=u8::MAX {
+        let (r, g, b) = byte_to_rgb3(byte);
+        assert_eq!(
+            rgb_from_byte(byte),
+            (color3_to_byte(r), color3_to_byte(g), color3_to_byte(b)),
+            "byte {byte:#010b}"
+        );
+    }
+}
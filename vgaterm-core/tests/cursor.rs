@@ -0,0 +1,23 @@
+//! Cursor positions moved around the grid
+
+use vgaterm_core::terminal::CursorPos;
+
+#[test]
+fn offset() {
+    let (rows, cols) = (33, 105);
+    assert_eq!(CursorPos(0, 0).offset(0, 1, rows, cols), CursorPos(0, 1));
+    assert_eq!(CursorPos(0, 1).offset(0, -1, rows, cols), CursorPos(0, 0));
+
+    // columns saturate
+    assert_eq!(
+        CursorPos(0, 0).offset(0, 107, rows, cols),
+        CursorPos(0, 104)
+    );
+    assert_eq!(CursorPos(1, 0).offset(0, -105, rows, cols), CursorPos(1, 0));
+    assert_eq!(CursorPos(2, 0).offset(0, -106, rows, cols), CursorPos(2, 0));
+
+    // as do rows
+    assert_eq!(CursorPos(0, 0).offset(34, 0, rows, cols), CursorPos(32, 0));
+    assert_eq!(CursorPos(1, 0).offset(-2, 0, rows, cols), CursorPos(0, 0));
+    assert_eq!(CursorPos(0, 0).offset(-1, -1, rows, cols), CursorPos(0, 0));
+}
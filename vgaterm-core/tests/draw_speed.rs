@@ -0,0 +1,57 @@
+//! The workstation counterpart of the firmware's `draw_bench`: the same stream
+//! of host output is typed into a terminal with each renderer, and only the
+//! drawing is timed, per byte typed. It's a comparison between the renderers
+//! rather than a measure of the esp32c3, so it's left out of the normal run:
+//!
+//! ```text
+//! cargo test-core --release --test draw_speed -- --ignored --nocapture
+//! ```
+
+use std::time::{Duration, Instant};
+
+use vgaterm_core::{display::Renderer, framebuffer::FrameBuffer, terminal::TextField};
+
+const ESC: &str = "\u{1B}";
+
+/// Something like a build log: lines of varying length, some of them colored,
+/// which scroll the screen
+fn host_output() -> String {
+    let mut out = String::new();
+    for line in 0..4000 {
+        let color = 31 + line % 7;
+        let words = "Compiling vgaterm-core v0.1.0 (/src/vgaterm-core) ".repeat(1 + line % 2);
+        out.push_str(&format!("{ESC}[1;{color}m{line:>5}{ESC}[0m {words}\r\n"));
+    }
+    out
+}
+
+/// Nanoseconds spent drawing per byte of `output`, typed in reads the size a
+/// serial port hands over and drawn after each
+fn ns_per_byte(renderer: Renderer, output: &str) -> f64 {
+    let mut terminal = TextField::new();
+    terminal.text.set_renderer(renderer);
+    let mut frame = FrameBuffer::new();
+    let mut drawing = Duration::ZERO;
+    for read in output.as_bytes().chunks(600) {
+        terminal.type_bytes(read);
+        let start = Instant::now();
+        terminal.draw(&mut frame);
+        drawing += start.elapsed();
+    }
+    drawing.as_nanos() as f64 / output.len() as f64
+}
+
+#[test]
+#[ignore]
+fn draw_speed() {
+    let output = host_output();
+    // Once through first, so neither is timed from cold
+    ns_per_byte(Renderer::Glyphs, &output);
+    let glyphs = ns_per_byte(Renderer::Glyphs, &output);
+    let text = ns_per_byte(Renderer::Text, &output);
+    println!(
+        "{} bytes: Glyphs {glyphs:.1} ns per byte, Text {text:.1} ns per byte, {:.1}x",
+        output.len(),
+        text / glyphs
+    );
+}
@@ -0,0 +1,716 @@
+//! Golden image tests: feed a byte script through `TextField::type_str`, render
+//! the terminal into a `FrameBuffer`, and compare the result pixel by pixel
+//! with a reference image checked in under `tests/golden/`.
+//!
+//! After an intentional rendering change, re-record the references with
+//!
+//! ```text
+//! VGATERM_BLESS=1 cargo test-core
+//! ```
+//!
+//! and check in the new images. On a mismatch, the actual frame and a diff
+//! (changed pixels in red over a dimmed copy of the reference) are written to
+//! the cargo target directory, and the failure lists the glyph cells that
+//! changed.
+
+use std::{
+    collections::BTreeSet,
+    env, fs, io,
+    path::{Path, PathBuf},
+};
+
+use vgaterm_core::{
+    color,
+    display::Renderer,
+    framebuffer::{Frame, FrameBuffer, HEIGHT, WIDTH},
+    scrollback,
+    terminal::TextField,
+    text::FONT_6X12,
+};
+
+const ESC: &str = "\u{1B}";
+
+#[test]
+fn plain_text() {
+    golden(
+        "plain_text",
+        "Hello, World!\r\nThe quick brown fox jumps over the lazy dog.\r\n",
+    );
+}
+
+#[test]
+fn alternate_screen() {
+    // Whatever happens on the alternate screen, leaving it should get back
+    // exactly the frame from `plain_text`
+    let mut script =
+        String::from("Hello, World!\r\nThe quick brown fox jumps over the lazy dog.\r\n");
+    script.push_str(&format!(
+        "{ESC}[?1049h{ESC}[41mfull screen{ESC}[10;10H{ESC}[0m"
+    ));
+    script.push_str(&"\n".repeat(40));
+    script.push_str(&format!("{ESC}[?1049l"));
+    golden("plain_text", &script);
+}
+
+#[test]
+fn alternate_screen_modes() {
+    // Mode 47 switches screens without clearing
+    let text = "Hello, World!\r\nThe quick brown fox jumps over the lazy dog.\r\n";
+    golden("plain_text", &format!("{text}{ESC}[?47h"));
+
+    // and 1049 puts back the colors along with the cursor
+    let [left, expected] = [
+        format!("{ESC}[31m{ESC}[?1049h{ESC}[32;44m{ESC}[?1049lred"),
+        format!("{ESC}[31mred"),
+    ]
+    .map(|script| {
+        let mut terminal = TextField::new();
+        let mut frame = FrameBuffer::new();
+        terminal.type_str(&script);
+        terminal.draw(&mut frame);
+        to_rgb(&frame)
+    });
+    assert!(
+        left == expected,
+        "leaving the alternate screen kept its colors"
+    );
+}
+
+#[test]
+fn line_wrap() {
+    let line = "0123456789".repeat(11);
+    golden("line_wrap", &line);
+}
+
+#[test]
+fn autowrap() {
+    let long = "0123456789".repeat(11);
+    let full = &long[..105];
+    // A line that exactly fills the width, then CR LF, leaves no blank line
+    let mut script = format!("{full}\r\nno blank line above\r\n");
+    // The wrap waits for the next character
+    script.push_str(&format!("{full}X\r\n"));
+    // and moving the cursor calls it off
+    script.push_str(&format!("{full}{ESC}[4DY\r\n"));
+    // Without DECAWM, the rest of the line goes over the last column
+    script.push_str(&format!("{ESC}[?7l{long}{ESC}[?7h\r\ndone"));
+    golden("autowrap", &script);
+}
+
+#[test]
+fn basic_colors() {
+    let mut script = String::new();
+    for fg in 30..=37 {
+        script.push_str(&format!("{ESC}[{fg}mfg {fg} "));
+    }
+    script.push_str("\r\n");
+    for bg in 40..=47 {
+        script.push_str(&format!("{ESC}[{bg}mbg {bg} "));
+    }
+    script.push_str(&format!("{ESC}[0m\r\n"));
+    for fg in 90..=97 {
+        script.push_str(&format!("{ESC}[{fg}mfg {fg} "));
+    }
+    golden("basic_colors", &script);
+}
+
+#[test]
+fn colors_256() {
+    let mut script = String::new();
+    for c in 0..=255 {
+        script.push_str(&format!("{ESC}[48;5;{c}m "));
+        if c % 64 == 63 {
+            script.push_str(&format!("{ESC}[0m\r\n"));
+        }
+    }
+    golden("colors_256", &script);
+}
+
+#[test]
+fn truecolor() {
+    let mut script = String::new();
+    // Red, green, blue and grey ramps, in every spelling of direct color
+    for (i, form) in [
+        "48;2;{v};0;0",
+        "48:2::0:{v}:0",
+        "48:2:0:0:{v}",
+        "48;2;{v};{v};{v}",
+    ]
+    .iter()
+    .enumerate()
+    {
+        for step in 0..64 {
+            let sgr = form.replace("{v}", &(step * 4).to_string());
+            script.push_str(&format!("{ESC}[{sgr}m "));
+        }
+        script.push_str(&format!("{ESC}[0m {i}\r\n"));
+    }
+    script.push_str(&format!("{ESC}[38;2;255;128;0morange {ESC}[38:5:33mazure"));
+    golden("truecolor", &script);
+}
+
+#[test]
+fn decorations() {
+    let script = format!(
+        "{ESC}[4munderline{ESC}[24m {ESC}[9mstrike{ESC}[29m {ESC}[7minverse{ESC}[27m plain\r\n\
+         {ESC}[3mitalic{ESC}[23m {ESC}[3;4munderlined italic{ESC}[0m {ESC}[1;3;38;5;208mbold italic\r\n"
+    );
+    golden("decorations", &script);
+    // Drawn through embedded-graphics rather than straight into the frame,
+    // which has to come out the same
+    golden_with("decorations", &script, |terminal| {
+        terminal.text.set_renderer(Renderer::Text)
+    });
+}
+
+#[test]
+fn blink() {
+    let script = format!(
+        "{ESC}[5;4mblink{ESC}[0m steady {ESC}[5;9mgone{ESC}[0m\r\n{ESC}[4msteady underline{ESC}[0m"
+    );
+    let mut terminal = TextField::new();
+    let mut frame = FrameBuffer::new();
+    terminal.type_str(&script);
+    terminal.draw(&mut frame);
+    let shown = to_rgb(&frame);
+
+    // Each phase change redraws only the cells that blink
+    let blinking = "blink".len() + "gone".len();
+    terminal.text.blink();
+    assert_eq!(terminal.text.num_dirty(), blinking);
+    terminal.draw(&mut frame);
+    terminal.text.blink();
+    assert_eq!(terminal.text.num_dirty(), blinking);
+    terminal.draw(&mut frame);
+    assert!(
+        to_rgb(&frame) == shown,
+        "blinking back on left a different frame"
+    );
+
+    // Hidden, they take their underline and strikethrough with them
+    golden_with("blink_hidden", &script, |terminal| terminal.text.blink());
+    golden_with("blink_hidden", &script, |terminal| {
+        terminal.text.set_renderer(Renderer::Text);
+        terminal.text.blink();
+    });
+}
+
+#[test]
+fn cursor_shapes() {
+    // Steady, so it's showing whenever it's drawn. The text underneath is left
+    // as it was, inverse included.
+    let text = format!("{ESC}[7minverse{ESC}[0m plain{ESC}[1;3H");
+    golden("cursor_underline", &format!("{text}{ESC}[4 q"));
+    golden("cursor_bar", &format!("{text}{ESC}[6 q"));
+    golden_with("cursor_bar", &format!("{text}{ESC}[6 q"), |terminal| {
+        terminal.text.set_renderer(Renderer::Text)
+    });
+}
+
+#[test]
+fn cursor_overlay() {
+    let inverse = format!("{ESC}[7minverse{ESC}[0m");
+    let mut terminal = TextField::new();
+    let mut frame = FrameBuffer::new();
+    terminal.type_str(&inverse);
+    terminal.draw(&mut frame);
+
+    // Moving the cursor only redraws the cell it left and the one it's on
+    terminal.type_str(&format!("{ESC}[1;3H"));
+    terminal.draw_up_to(0, &mut frame);
+    assert_eq!(terminal.text.num_dirty(), 2);
+    terminal.draw(&mut frame);
+
+    // and moving on leaves the text it passed over as it was
+    terminal.type_str(&format!("{ESC}[3;1H"));
+    terminal.draw(&mut frame);
+    assert!(terminal.text.read_char(0, 2).color.inverse());
+
+    let mut expected = FrameBuffer::new();
+    let mut whole = TextField::new();
+    whole.type_str(&format!("{inverse}{ESC}[3;1H"));
+    whole.draw(&mut expected);
+    assert!(
+        to_rgb(&frame) == to_rgb(&expected),
+        "the cursor left something behind"
+    );
+}
+
+#[test]
+fn box_drawing() {
+    let mut script = String::from("┌─┬─┐ ╔═╦═╗ ┏━┳━┓ ╭─╮ ▀▄█▌▐░▒▓ ▖▗▘▙▚▛▜▝▞▟\r\n");
+    script.push_str("├─┼─┤ ╠═╬═╣ ┣━╋━┫ │ │ ▁▂▃▄▅▆▇█ ▏▎▍▌▋▊▉ ╱╲╳\r\n");
+    script.push_str("└─┴─┘ ╚═╩═╝ ┗━┻━┛ ╰─╯ ┄┅┆┇┈┉┊┋ ╒╕╓╖ ╘╛╙╜\r\n");
+    // Powerline prompts, pointing both ways
+    script.push_str(&format!(
+        "{ESC}[44m \u{E0A0} main \u{E0A1} 12 \u{E0A2} {ESC}[0;34m\u{E0B0}{ESC}[0m \u{E0B1} "
+    ));
+    script.push_str(&format!(
+        "\u{E0B3} {ESC}[34m\u{E0B2}{ESC}[44;37m right {ESC}[0m\r\n"
+    ));
+    // Wide characters take two cells, and one that doesn't fit at the end of
+    // a line goes on the next
+    script.push_str("漢字 [日本語] x\u{301}y\r\n");
+    script.push_str(&format!("{}界界", "-".repeat(104)));
+    // Writing over half of a wide character blanks the other half
+    script.push_str(&format!("{ESC}[5;1H#{ESC}[5;4H#"));
+    golden("box_drawing", &script);
+}
+
+#[test]
+fn powerline_glyphs() {
+    // None of Powerline's symbols fall back to the replacement character
+    let mapping = FONT_6X12.glyphs.glyph_mapping;
+    let replacement = mapping.index('\u{FFFD}');
+    for c in ('\u{E0A0}'..='\u{E0A2}').chain('\u{E0B0}'..='\u{E0B3}') {
+        assert_ne!(
+            mapping.index(c),
+            replacement,
+            "no glyph for U+{:04X}",
+            c as u32
+        );
+    }
+}
+
+#[test]
+fn dec_line_drawing() {
+    // A box the way curses draws one, with the line drawing set in G0
+    let mut script =
+        format!("{ESC}(0lqqqqwqqqqk\r\nx AB1x 23 x\r\ntqqqqnqqqqu\r\nmqqqqvqqqqj{ESC}(B\r\n");
+    // and the rest of the set, shifted in from G1 with SO and back out with SI
+    script.push_str(&format!(
+        "{ESC})0ascii \u{0E}_`abcdefghijklmnopqrstuvwxyz{{|}}~\u{0F} ascii"
+    ));
+    golden("dec_line_drawing", &script);
+}
+
+#[test]
+fn fonts() {
+    // 80x25 and 128x50 grids, each written out to the far corner, after
+    // starting out in the default font to check nothing of it is left behind
+    let fill = "The quick brown fox jumps over the lazy dog. ".repeat(3);
+    for (n, name, rows, cols) in [(1, "font_8x16", 25, 80), (2, "font_5x8", 50, 128)] {
+        // Short of the last column, where writing would wrap and scroll
+        let col = cols - 1;
+        let script = format!(
+            "{fill}{ESC}[>{n}F{ESC}[44m{name}{ESC}[0m {ESC}[7minverse{ESC}[0m \
+             {ESC}[4munderline{ESC}[0m £ é{ESC}[{rows};{col}H#"
+        );
+        golden(name, &script);
+        // Underline and all, the Text renderer has to agree
+        golden_with(name, &script, |terminal| {
+            terminal.text.set_renderer(Renderer::Text)
+        });
+    }
+}
+
+#[test]
+fn split_utf8() {
+    // Two, three and four byte characters, then a stray continuation byte, a
+    // truncated sequence cut off by ASCII, an overlong form and a surrogate
+    let mut bytes = "é ─ 漢 \u{1F600} ".as_bytes().to_vec();
+    bytes.extend_from_slice(b"\x80 \xE2\x94x \xC0\xAF \xED\xA0\x80 ok");
+    // However the bytes are split between reads, they should come out the same
+    for split in 0..=bytes.len() {
+        golden_with("split_utf8", "", |terminal| {
+            terminal.type_bytes(&bytes[..split]);
+            terminal.type_bytes(&bytes[split..]);
+        });
+    }
+}
+
+#[test]
+fn bold_and_dim() {
+    let mut script = String::new();
+    for (sgr, name) in [("0", "plain"), ("1", "bold"), ("2", "dim")] {
+        for fg in 30..=37 {
+            script.push_str(&format!("{ESC}[0;{sgr};{fg}m{name} "));
+        }
+        script.push_str("\r\n");
+    }
+    // Without a bright variant to use, bold thickens the glyphs instead
+    script.push_str(&format!("{ESC}[0;1;38;5;208mbold orange{ESC}[22m normal"));
+    golden("bold_and_dim", &script);
+}
+
+#[test]
+fn erase_and_move() {
+    golden(
+        "erase_and_move",
+        &format!(
+            "aaaaaaaaaa\r\nbbbbbbbbbb\r\ncccccccccc{ESC}[2;5H{ESC}[K{ESC}[3;1H{ESC}[2C{ESC}[1K{ESC}[10;20Hhere"
+        ),
+    );
+}
+
+#[test]
+fn insert_delete() {
+    let mut script = String::new();
+    for row in 0..6 {
+        script.push_str(&format!("line {row}: abcdefghij\r\n"));
+    }
+    // ICH, DCH and ECH on the first three lines, then IL and DL further down
+    script.push_str(&format!(
+        "{ESC}[1;9H{ESC}[3@{ESC}[2;9H{ESC}[3P{ESC}[3;9H{ESC}[3X"
+    ));
+    script.push_str(&format!("{ESC}[4;1H{ESC}[2L{ESC}[7;1H{ESC}[M"));
+    golden("insert_delete", &script);
+}
+
+#[test]
+fn insert_delete_wide() {
+    // ICH and DCH cutting through a wide character blank both halves of it,
+    // rather than leave half a character behind
+    let mut terminal = TextField::new();
+    let last = terminal.text.columns() - 1;
+    // ICH on the right half of 漢, and with 字 half pushed off the right edge
+    terminal.type_str(&format!("a漢b{ESC}[1;3H{ESC}[@"));
+    terminal.type_str(&format!("{ESC}[2;{last}H字{ESC}[2;1H{ESC}[@"));
+    // DCH from the right half of 漢 to the left half of 字
+    terminal.type_str(&format!("{ESC}[3;1H漢字b{ESC}[3;2H{ESC}[2P"));
+
+    let text = &terminal.text;
+    let cells = |row, cols: std::ops::Range<usize>| -> String {
+        cols.map(|col| text.read_char(row, col).char()).collect()
+    };
+    assert_eq!(cells(0, 0..5), "a   b");
+    assert_eq!(cells(1, last - 2..last + 1), "   ");
+    assert_eq!(cells(2, 0..4), "  b ");
+    for row in 0..3 {
+        for col in 0..=last {
+            let ch = text.read_char(row, col);
+            assert!(!ch.is_wide() && !ch.is_wide_right(), "({row}, {col})");
+        }
+    }
+}
+
+#[test]
+fn tab_stops() {
+    // Every 8 columns to start with, and forward or back a number of stops
+    let mut script = String::from("1\t2\t3\tend\r\n");
+    script.push_str(&format!("{ESC}[2Iforward{ESC}[2Zback\r\n"));
+    // Stops of our own at 5 and 21, then the one at 21 cleared on the next
+    // line. Past the last stop is the end of the line.
+    script.push_str(&format!("{ESC}[3g{ESC}[3;5H{ESC}H{ESC}[3;21H{ESC}H"));
+    script.push_str(&format!("{ESC}[3;1H\tA\tB\tC"));
+    script.push_str(&format!("{ESC}[4;21H{ESC}[g{ESC}[4;1H\tD\tE"));
+    golden("tab_stops", &script);
+}
+
+#[test]
+fn control_chars() {
+    // Text outside ASCII prints as itself
+    let mut script = String::from("caf\u{e9} \u{fc}ber na\u{ef}ve\r\n");
+    // NUL and BEL do nothing, and BS only moves back
+    script.push_str("a\0b\u{7}c\u{8}\u{8}X\r\n");
+    // VT, FF and C1 NEL each start a new line
+    script.push_str("vt\u{b}ff\u{c}nel\u{85}end");
+    golden("control_chars", &script);
+}
+
+#[test]
+fn scroll_region() {
+    let mut script = format!("status line\r\n{ESC}[2;6r");
+    // Lines 2 to 6 scroll, leaving the status line and line 7 alone
+    script.push_str(&format!("{ESC}[7;1Hbottom line{ESC}[2;1H"));
+    for row in 0..8 {
+        script.push_str(&format!("\nscrolled {row}"));
+    }
+    // Scroll the region back down with RI at its top, then once more with SD
+    script.push_str(&format!("{ESC}[2;1H{ESC}M{ESC}[T"));
+    // and text outside the region can still be written over
+    script.push_str(&format!("{ESC}[1;4Hwhole screen"));
+    golden("scroll_region", &script);
+}
+
+#[test]
+fn scroll_region_margins() {
+    // CUU and CUD from inside the region stop at its top and bottom lines
+    let mut terminal = TextField::new();
+    terminal.type_str(&format!("{ESC}[5;10r{ESC}[7;3H{ESC}[20A"));
+    assert_eq!(terminal.type_str(&format!("{ESC}[6n")), b"\x1b[5;3R");
+    terminal.type_str(&format!("{ESC}[20B"));
+    assert_eq!(terminal.type_str(&format!("{ESC}[6n")), b"\x1b[10;3R");
+
+    // but from outside it they go as far as the edge of the screen
+    terminal.type_str(&format!("{ESC}[12;3H{ESC}[20B"));
+    assert_eq!(terminal.type_str(&format!("{ESC}[6n")), b"\x1b[32;3R");
+}
+
+#[test]
+fn scrollback_view() {
+    let mut script = String::new();
+    for line in 0..50 {
+        script.push_str(&format!("line {line}\r\n"));
+    }
+    // Lines 0 to 17 have scrolled off, and the top 10 rows show 8 to 17
+    golden_with("scrollback_view", &script, |terminal| {
+        terminal.scroll_view(10)
+    });
+}
+
+#[test]
+fn scrollback_view_drawn_clean() {
+    // Paged back, the bottom of the live screen is out of view, and a full
+    // draw leaves none of it marked, even after more is written there
+    let mut terminal = TextField::new();
+    let mut frame = FrameBuffer::new();
+    for line in 0..50 {
+        terminal.type_str(&format!("line {line}\r\n"));
+    }
+    terminal.scroll_view(10);
+    terminal.draw(&mut frame);
+    assert_eq!(terminal.text.num_dirty(), 0);
+
+    terminal.type_str(&format!("{ESC}[30;1Hout of view"));
+    terminal.draw(&mut frame);
+    assert_eq!(terminal.text.num_dirty(), 0);
+}
+
+#[test]
+fn scrollback_blank_lines() {
+    // Blank lines still count against the budget, so a screen of nothing
+    // scrolling by can't grow the history without end
+    let mut terminal = TextField::new();
+    terminal.type_str(&"\n".repeat(10_000));
+    let kept = terminal.text.scrollback.len();
+    assert!(kept > 0 && kept <= scrollback::DEFAULT_CELLS / 2);
+
+    // and the alternate screen keeps none at all
+    terminal.type_str(&format!("{ESC}[?1049h"));
+    terminal.type_str(&"\n".repeat(100));
+    assert_eq!(terminal.text.scrollback.len(), 0);
+}
+
+#[test]
+fn scrollback_skips_deleted_lines() {
+    // Lines DL deletes off the top of the screen are gone, not scrolled off
+    // into the history
+    let mut terminal = TextField::new();
+    terminal.type_str(&format!("top line\r\n{ESC}[H{ESC}[M{ESC}[3M"));
+    assert_eq!(terminal.text.scrollback.len(), 0);
+    terminal.type_str(&format!("{ESC}[L"));
+    assert_eq!(terminal.text.scrollback.len(), 0);
+}
+
+#[test]
+fn redraw_budget() {
+    let digits = "0123456789".repeat(20);
+    let script =
+        format!("overwritten\rOVERWRITTEN twice{ESC}[3;5H{ESC}[1;31mred{ESC}[0m\r\n{digits}");
+
+    // A cell written over is still only one to draw
+    let mut once = TextField::new();
+    once.type_str("overwritten");
+    let mut twice = TextField::new();
+    twice.type_str("overwritten\roverwritten\rOVERWRITTEN");
+    assert_eq!(once.text.num_dirty(), twice.text.num_dirty());
+
+    let mut whole = TextField::new();
+    whole.type_str(&script);
+    let mut expected = FrameBuffer::new();
+    while whole.text.num_dirty() > 0 {
+        whole.draw(&mut expected);
+    }
+
+    // Drawn a few cells at a time, it comes out the same
+    let mut partial = TextField::new();
+    partial.type_str(&script);
+    let mut frame = FrameBuffer::new();
+    while partial.text.num_dirty() > 0 {
+        partial.draw_up_to(7, &mut frame);
+    }
+    assert!(
+        to_rgb(&frame) == to_rgb(&expected),
+        "drawing 7 cells at a time left a different frame"
+    );
+}
+
+#[test]
+fn hardware_scroll() {
+    let lines: Vec<String> = (0..50)
+        .map(|line| format!("{ESC}[3{}mline {line}\r\n", 1 + line % 7))
+        .collect();
+
+    // Drawn a line at a time, the frame scrolls by moving where it starts
+    let mut terminal = TextField::new();
+    let mut frame = FrameBuffer::new();
+    for line in &lines {
+        terminal.type_str(line);
+        terminal.draw(&mut frame);
+    }
+    assert_eq!(frame.top(), 18 * FONT_6X12.cell.height as usize);
+
+    // and shows the same as drawing it all at the end
+    let mut whole = TextField::new();
+    let mut expected = FrameBuffer::new();
+    whole.type_str(&lines.concat());
+    whole.draw(&mut expected);
+    assert!(
+        to_rgb(&frame) == to_rgb(&expected),
+        "scrolling the frame left it different"
+    );
+    golden("hardware_scroll", &lines.concat());
+}
+
+#[test]
+fn tear_free() {
+    let script = format!("{ESC}[1;31mtop line{ESC}[30;1Hbottom line");
+    let scroll = "\n".repeat(5);
+
+    // With only the bottom half ready, the top line waits
+    let mut terminal = TextField::new();
+    let mut frame = FrameBuffer::new();
+    terminal.type_str(&script);
+    frame.set_drawable_rows(200..HEIGHT);
+    terminal.draw(&mut frame);
+    assert!(terminal.text.num_dirty() > 0);
+    assert!(
+        (0..200).all(|y| (0..WIDTH).all(|x| frame.pixel(x, y) == 0)),
+        "drew above the drawable rows"
+    );
+
+    // and so does a scroll, which moves all of it
+    terminal.type_str(&scroll);
+    terminal.draw(&mut frame);
+    assert_eq!(frame.top(), 0);
+
+    frame.set_drawable_rows(0..HEIGHT);
+    terminal.draw(&mut frame);
+
+    let mut whole = TextField::new();
+    let mut expected = FrameBuffer::new();
+    whole.type_str(&format!("{script}{scroll}"));
+    whole.draw(&mut expected);
+    assert!(
+        to_rgb(&frame) == to_rgb(&expected),
+        "catching up left a different frame"
+    );
+}
+
+/// Run `script` through a fresh terminal and compare the frame against the
+/// reference image `tests/golden/<name>.png`
+fn golden(name: &str, script: &str) {
+    golden_with(name, script, |_| {});
+}
+
+/// Like `golden`, but with a go at the terminal before it's drawn, for what
+/// can't be done from the host side
+fn golden_with(name: &str, script: &str, then: impl FnOnce(&mut TextField)) {
+    let mut terminal = TextField::new();
+    let mut frame = FrameBuffer::new();
+    terminal.type_str(script);
+    then(&mut terminal);
+    terminal.draw(&mut frame);
+    let actual = to_rgb(&frame);
+
+    let reference_path = Path::new(env!("CARGO_MANIFEST_DIR"))
+        .join("tests/golden")
+        .join(format!("{name}.png"));
+
+    if env::var_os("VGATERM_BLESS").is_some() {
+        fs::create_dir_all(reference_path.parent().unwrap()).unwrap();
+        write_png(&reference_path, &actual);
+        return;
+    }
+
+    let Some(expected) = read_png(&reference_path) else {
+        panic!(
+            "no reference image at {}, record one with VGATERM_BLESS=1",
+            reference_path.display()
+        );
+    };
+
+    if expected == actual {
+        return;
+    }
+
+    let mut cells = BTreeSet::new();
+    let mut diff = Vec::with_capacity(actual.len());
+    for (i, (e, a)) in expected.chunks(3).zip(actual.chunks(3)).enumerate() {
+        if e == a {
+            diff.extend(e.iter().map(|c| c / 3));
+        } else {
+            diff.extend_from_slice(&[255, 0, 0]);
+            cells.insert(cell_at(i % WIDTH, i / WIDTH));
+        }
+    }
+
+    let out = output_dir();
+    let actual_path = out.join(format!("{name}.actual.png"));
+    let diff_path = out.join(format!("{name}.diff.png"));
+    write_png(&actual_path, &actual);
+    write_png(&diff_path, &diff);
+
+    panic!(
+        "{name}: {} glyph cell(s) differ from the reference, as (row, col): {:?}\n  actual: {}\n  diff:   {}",
+        cells.len(),
+        cells,
+        actual_path.display(),
+        diff_path.display(),
+    );
+}
+
+/// The (row, col) of the text cell that covers pixel (x, y), in the default
+/// font's grid. See `TextDisplay::draw_character` for where glyphs are placed.
+fn cell_at(x: usize, y: usize) -> (usize, usize) {
+    let font = FONT_6X12;
+    (
+        y.saturating_sub(font.origin.y as usize) / font.cell.height as usize,
+        x.saturating_sub(font.origin.x as usize) / font.cell.width as usize,
+    )
+}
+
+/// Decode the hardware color bytes the way the monitor would see them, from
+/// the top of the picture down
+fn to_rgb(frame: &FrameBuffer) -> Vec<u8> {
+    let mut rgb = Vec::with_capacity(WIDTH * HEIGHT * 3);
+    for y in 0..HEIGHT {
+        for x in 0..WIDTH {
+            let (r, g, b) = color::rgb_from_byte(frame.pixel(x, y));
+            rgb.extend_from_slice(&[r, g, b]);
+        }
+    }
+    rgb
+}
+
+fn output_dir() -> PathBuf {
+    let dir = Path::new(env!("CARGO_TARGET_TMPDIR")).join("golden");
+    fs::create_dir_all(&dir).unwrap();
+    dir
+}
+
+fn write_png(path: &Path, rgb: &[u8]) {
+    let file = fs::File::create(path).unwrap_or_else(|e| panic!("writing {}: {e}", path.display()));
+    let mut encoder = png::Encoder::new(io::BufWriter::new(file), WIDTH as u32, HEIGHT as u32);
+    encoder.set_color(png::ColorType::Rgb);
+    encoder.set_depth(png::BitDepth::Eight);
+    encoder.set_compression(png::Compression::Best);
+    encoder
+        .write_header()
+        .and_then(|mut writer| writer.write_image_data(rgb))
+        .unwrap_or_else(|e| panic!("writing {}: {e}", path.display()));
+}
+
+/// Reads back an RGB PNG as written by `write_png`
+fn read_png(path: &Path) -> Option<Vec<u8>> {
+    let file = fs::File::open(path).ok()?;
+    let mut reader = png::Decoder::new(io::BufReader::new(file))
+        .read_info()
+        .unwrap_or_else(|e| panic!("reading {}: {e}", path.display()));
+    let mut pixels = vec![0; reader.output_buffer_size()];
+    let info = reader
+        .next_frame(&mut pixels)
+        .unwrap_or_else(|e| panic!("reading {}: {e}", path.display()));
+
+    let format = (info.width, info.height, info.color_type, info.bit_depth);
+    let expected = (
+        WIDTH as u32,
+        HEIGHT as u32,
+        png::ColorType::Rgb,
+        png::BitDepth::Eight,
+    );
+    assert_eq!(format, expected, "unexpected format of {}", path.display());
+    pixels.truncate(info.buffer_size());
+    Some(pixels)
+}
@@ -0,0 +1,196 @@
+//! The escape sequence parser on its own, fed a char at a time the way the
+//! terminal feeds it. Sequences it doesn't act on still have to be consumed
+//! whole, leaving the parser back in the ground state, which each test checks
+//! by following the sequence with some text that has to come out as it went in.
+
+use vgaterm_core::ansi::{Op, OpChar, Parser, TextOp, Vgaterm};
+
+const ESC: &str = "\u{1B}";
+
+/// What the parser hands out for `input`, one char at a time
+fn feed(input: &str) -> Vec<OpChar> {
+    let mut parser = Parser::new();
+    let mut out = Vec::new();
+    for c in input.chars() {
+        parser.advance(c, |op| out.push(op));
+    }
+    out
+}
+
+/// `out` ends with the chars of "ok", and nothing else was printed
+fn back_on_the_ground(out: &[OpChar]) -> bool {
+    let printed: String = out
+        .iter()
+        .filter_map(|op| match op {
+            OpChar::Char(c) => Some(*c),
+            OpChar::Op(_) => None,
+        })
+        .collect();
+    printed == "ok" && matches!(out[out.len() - 2..], [OpChar::Char('o'), OpChar::Char('k')])
+}
+
+#[test]
+fn unknown_csi_with_intermediates() {
+    let out = feed(&format!("{ESC}[1;2 $zok"));
+    assert!(back_on_the_ground(&out), "{out:?}");
+    assert_eq!(out.len(), 2);
+
+    // A final we know doesn't count with intermediates in front of it
+    let out = feed(&format!("{ESC}[2!Jok"));
+    assert_eq!(out.len(), 2, "{out:?}");
+    assert!(back_on_the_ground(&out));
+
+    // nor with more intermediates than are kept
+    let out = feed(&format!("{ESC}[ !\"#Hok"));
+    assert_eq!(out.len(), 2, "{out:?}");
+    assert!(back_on_the_ground(&out));
+}
+
+#[test]
+fn osc_ended_by_bel() {
+    let out = feed(&format!("{ESC}]0;a title; with [brackets]\u{7}ok"));
+    assert_eq!(out.len(), 2, "{out:?}");
+    assert!(back_on_the_ground(&out));
+}
+
+#[test]
+fn osc_ended_by_st() {
+    // Both the 7 bit ST, `ESC \`, and the C1 one
+    let out = feed(&format!("{ESC}]2;title{ESC}\\ok"));
+    assert_eq!(out.len(), 2, "{out:?}");
+    assert!(back_on_the_ground(&out));
+
+    let out = feed("\u{9D}2;title\u{9C}ok");
+    assert_eq!(out.len(), 2, "{out:?}");
+    assert!(back_on_the_ground(&out));
+}
+
+#[test]
+fn dcs_passthrough() {
+    // Sixel data, say, which goes nowhere
+    let out = feed(&format!("{ESC}P0;1;0q\"1;1;8;8#0;2;0;0;0#0!8~-{ESC}\\ok"));
+    assert_eq!(out.len(), 2, "{out:?}");
+    assert!(back_on_the_ground(&out));
+
+    // and one that goes wrong in its parameters is still consumed whole
+    let out = feed(&format!("{ESC}P1:2|data\u{9C}ok"));
+    assert_eq!(out.len(), 2, "{out:?}");
+    assert!(back_on_the_ground(&out));
+}
+
+#[test]
+fn too_many_params() {
+    // Past the 16th, parameters are dropped, but the sequence still applies
+    let extra = ";7".repeat(20);
+    let out = feed(&format!("{ESC}[5;10{extra}Hok"));
+    assert!(
+        matches!(out[0], OpChar::Op(Op::MoveCursorAbs { x: 9, y: 4 })),
+        "{out:?}"
+    );
+    assert_eq!(out.len(), 3);
+    assert!(back_on_the_ground(&out));
+
+    // and values saturate rather than overflow
+    let out = feed(&format!("{ESC}[123456789Bok"));
+    assert!(
+        matches!(out[0], OpChar::Op(Op::MoveCursorDelta { dx: 0, dy: 9999 })),
+        "{out:?}"
+    );
+    assert!(back_on_the_ground(&out));
+}
+
+#[test]
+fn sub_params() {
+    let out = feed(&format!("{ESC}[1;38:5:196;48:2::1:2:3mok"));
+    assert!(
+        matches!(
+            out[..3],
+            [
+                OpChar::Op(Op::TextOp(TextOp::SetTextMode(..))),
+                OpChar::Op(Op::TextOp(TextOp::SetFGColor256 { fg: 196 })),
+                OpChar::Op(Op::TextOp(TextOp::SetBGColorRgb { r: 1, g: 2, b: 3 })),
+            ]
+        ),
+        "{out:?}"
+    );
+    assert!(back_on_the_ground(&out));
+}
+
+#[test]
+fn out_of_range_color() {
+    // 300 isn't a channel, but 0 and 1 are still part of the color rather than a
+    // reset and bold, so the whole 38 is dropped
+    let out = feed(&format!("{ESC}[38;2;300;0;1mok"));
+    assert_eq!(out.len(), 2, "{out:?}");
+    assert!(back_on_the_ground(&out));
+
+    // and the parameters after it still apply
+    let out = feed(&format!("{ESC}[48;5;256;9mok"));
+    assert!(
+        matches!(
+            out[..],
+            [OpChar::Op(Op::TextOp(TextOp::SetTextMode(..))), _, _]
+        ),
+        "{out:?}"
+    );
+    assert!(back_on_the_ground(&out));
+}
+
+#[test]
+fn can_and_sub_abort() {
+    for abort in ['\u{18}', '\u{1A}'] {
+        let out = feed(&format!("{ESC}[31{abort}ok"));
+        assert_eq!(out.len(), 2, "{out:?}");
+        assert!(back_on_the_ground(&out));
+
+        let out = feed(&format!("{ESC}]0;title{abort}ok"));
+        assert_eq!(out.len(), 2, "{out:?}");
+        assert!(back_on_the_ground(&out));
+
+        let out = feed(&format!("{ESC}P1$q{abort}ok"));
+        assert_eq!(out.len(), 2, "{out:?}");
+        assert!(back_on_the_ground(&out));
+    }
+}
+
+#[test]
+fn dec_private_modes() {
+    let out = feed(&format!("{ESC}[?1049;25hok"));
+    assert!(
+        matches!(
+            out[..2],
+            [
+                OpChar::Op(Op::DecPrivateSet(1049)),
+                OpChar::Op(Op::DecPrivateSet(25)),
+            ]
+        ),
+        "{out:?}"
+    );
+    assert!(back_on_the_ground(&out));
+}
+
+#[test]
+fn vgaterm_redraw() {
+    for redraw in [format!("{ESC}[>V"), format!("{ESC}[VxD")] {
+        let out = feed(&format!("{redraw}ok"));
+        assert!(
+            matches!(out[0], OpChar::Op(Op::Vgaterm(Vgaterm::Redraw))),
+            "{out:?}"
+        );
+        assert_eq!(out.len(), 3);
+        assert!(back_on_the_ground(&out));
+    }
+
+    // What turns out not to be the old redraw is printed
+    let out = feed(&format!("{ESC}[Vxok"));
+    assert!(matches!(
+        out[..],
+        [OpChar::Char('x'), OpChar::Char('o'), OpChar::Char('k')]
+    ));
+    let out = feed(&format!("{ESC}[V{ESC}[Aok"));
+    assert!(
+        matches!(out[0], OpChar::Op(Op::MoveCursorDelta { dx: 0, dy: -1 })),
+        "{out:?}"
+    );
+    assert!(back_on_the_ground(&out));
+}
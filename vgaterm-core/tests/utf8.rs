@@ -0,0 +1,80 @@
+//! The UTF-8 decoder on bad input, fed a byte at a time, against what
+//! `String::from_utf8_lossy` makes of the same bytes all at once
+//!
+//! ```text
+//! cargo test-core --test utf8
+//! ```
+
+use vgaterm_core::utf8::{Utf8Decoder, REPLACEMENT};
+
+/// What the decoder makes of `bytes`, with a sequence still unfinished at the
+/// end counted as a replacement character the way a lossy decode counts it
+fn decode(bytes: &[u8]) -> String {
+    let mut decoder = Utf8Decoder::new();
+    let mut out = String::new();
+    for &byte in bytes {
+        decoder.decode(byte, |c| out.push(c));
+    }
+    if decoder.pending() {
+        out.push(REPLACEMENT);
+    }
+    out
+}
+
+fn assert_lossy(bytes: &[u8]) {
+    assert_eq!(
+        decode(bytes),
+        String::from_utf8_lossy(bytes),
+        "bytes {bytes:02X?}"
+    );
+}
+
+#[test]
+fn valid() {
+    assert_lossy("a\u{e9}\u{6F22}\u{1F600}\u{10FFFF}".as_bytes());
+}
+
+#[test]
+fn stray_continuation_bytes() {
+    assert_lossy(b"a\x80b");
+    assert_lossy(b"\xBF\xBF");
+    assert_lossy(b"\xC3\xA9\xA9");
+}
+
+#[test]
+fn overlong() {
+    // C0 and C1 can only start an overlong two byte form
+    assert_lossy(b"\xC0\x80");
+    assert_lossy(b"\xC1\xBFa");
+    // and E0 80 would be an overlong three byte one
+    assert_lossy(b"\xE0\x80\x80");
+    assert_lossy(b"\xE0\x9F\xBF");
+    assert_lossy(b"\xF0\x8F\xBF\xBF");
+}
+
+#[test]
+fn surrogates() {
+    assert_lossy(b"\xED\xA0\x80");
+    assert_lossy(b"\xED\xBF\xBFa");
+    // The last code point before them is fine
+    assert_lossy(b"\xED\x9F\xBF");
+}
+
+#[test]
+fn past_max() {
+    assert_lossy(b"\xF4\x90\x80\x80");
+    assert_lossy(b"\xF5\x80\x80\x80");
+    assert_lossy(b"\xFF");
+}
+
+#[test]
+fn truncated() {
+    // Cut short by something that can't continue it, which is then taken as
+    // itself
+    assert_lossy(b"\xE6\x97a");
+    assert_lossy(b"\xF0\x9F\x98\xC3\xA9");
+    assert_lossy(b"\xC3\xE6\x97\xA5");
+    // and by the end of the input
+    assert_lossy(b"ab\xF0\x9F\x98");
+    assert_lossy(b"\xE6");
+}